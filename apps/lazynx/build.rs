@@ -0,0 +1,31 @@
+use std::process::Command;
+
+use chrono::Utc;
+
+/// Runs git with the arguments, returning its trimmed output if it succeeds.
+fn git(args: &[&str]) -> Option<String> {
+    let output = Command::new("git").args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    return String::from_utf8(output.stdout)
+        .ok()
+        .map(|stdout| stdout.trim().to_owned());
+}
+
+/// Embeds the git commit and the build date, which `--version` prints.
+fn main() {
+    let git_sha = git(&["rev-parse", "--short", "HEAD"]).unwrap_or(String::from("unknown"));
+    let build_date = Utc::now().format("%Y-%m-%d");
+
+    println!("cargo:rustc-env=LAZYNX_GIT_SHA={git_sha}");
+    println!("cargo:rustc-env=LAZYNX_BUILD_DATE={build_date}");
+
+    // Rebuild whenever the sources change or another commit is checked out.
+    println!("cargo:rerun-if-changed=src");
+    if let Some(git_dir) = git(&["rev-parse", "--absolute-git-dir"]) {
+        println!("cargo:rerun-if-changed={git_dir}/HEAD");
+        println!("cargo:rerun-if-changed={git_dir}/refs/heads");
+    }
+}
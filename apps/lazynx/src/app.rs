@@ -1,67 +1,104 @@
 use color_eyre::eyre::Result;
-use crossterm::event::Event as CrosstermEvent;
-use ratatui::widgets::Widget;
-
-use crate::{
-    app_status::AppStatus,
-    consts::{QUIT_KEY_CTRL_C, QUIT_KEY_CTRL_D},
-    shared::{
-        config::Config,
-        events::{Event, EventLoopHandler},
-        tui::Tui,
-    },
-    shell::IAppWidget,
+use crossterm::event::{Event as CrosstermEvent, KeyModifiers};
+use ratatui::{layout::Rect, Frame};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::shared::{
+    actions::Action,
+    component::Component,
+    config::Config,
+    events::Event,
+    keymap::{KeyContext, Keymap},
+    overlay::{ErrorBox, Overlay, OverlayStack},
+    process::ProcessHandler,
+    session::Session,
+    theme::Theme,
 };
 
 #[derive(Default)]
-pub struct App<TShell: Widget + IAppWidget + Clone> {
+pub struct App<TShell: Component + Default> {
+    keymap: Keymap,
+    overlays: OverlayStack,
     shell: TShell,
-    status: AppStatus,
+    theme: Theme,
 }
 
-impl<TShell: Widget + IAppWidget + Clone> App<TShell> {
-    pub fn run(
-        &mut self,
-        mut tui: Tui,
-        _config: Config,
-        mut event_loop: EventLoopHandler,
-    ) -> Result<()> {
-        self.shell.init()?;
-
-        while self.status == AppStatus::Running {
-            if let Ok(event) = event_loop.next() {
-                self.event_handler(event, &mut tui)?;
-            }
-        }
+impl<TShell: Component + Default> Component for App<TShell> {
+    fn register_action_handler(&mut self, tx: UnboundedSender<Action>) -> Result<()> {
+        self.shell.register_action_handler(tx)
+    }
 
-        Ok(())
+    fn register_process_handler(&mut self, handler: ProcessHandler) -> Result<()> {
+        self.shell.register_process_handler(handler)
     }
 
-    fn draw(&mut self, tui: &mut Tui) -> Result<()> {
-        tui.draw(|frame| {
-            frame.render_widget(self.shell.clone(), frame.area());
-        })?;
+    fn register_config_handler(&mut self, config: Config) -> Result<()> {
+        self.keymap = config.keymap.clone();
+        self.theme = config.theme;
+        self.shell.register_config_handler(config)
+    }
 
-        Ok(())
+    fn init(&mut self) -> Result<()> {
+        self.shell.init()
     }
 
-    fn event_handler(&mut self, event: Event, tui: &mut Tui) -> Result<()> {
-        match event {
-            Event::Render | Event::Crossterm(CrosstermEvent::Resize(_, _)) => self.draw(tui)?,
-            Event::Quit => self.quit(),
-            Event::Crossterm(CrosstermEvent::Key(key)) => {
-                if key == QUIT_KEY_CTRL_C || key == QUIT_KEY_CTRL_D {
-                    self.quit();
-                }
+    fn restore_session(&mut self, session: &Session) -> Result<()> {
+        self.shell.restore_session(session)
+    }
+
+    fn save_session(&self, session: &mut Session) {
+        self.shell.save_session(session);
+    }
+
+    fn handle_events(&mut self, event: Event) -> Option<Action> {
+        let (global_action, can_be_typed, is_bound_by_shell) = match &event {
+            Event::Crossterm(CrosstermEvent::Key(key)) => (
+                self.keymap.action_for(KeyContext::Global, key),
+                !key.modifiers
+                    .intersects(KeyModifiers::CONTROL | KeyModifiers::ALT),
+                self.shell
+                    .key_context()
+                    .and_then(|context| self.keymap.action_for(context, key))
+                    .is_some(),
+            ),
+            _ => (None, false, false),
+        };
+
+        if !self.overlays.is_empty() {
+            // Quitting and suspending work even while an overlay holds the
+            // input, unless they are bound to keys typed into it.
+            return match global_action {
+                Some(action @ (Action::Quit | Action::Suspend)) if !can_be_typed => Some(action),
+                _ => self.overlays.handle_events(event),
+            };
+        }
+
+        // The focused pane's bindings take precedence over the global ones.
+        if is_bound_by_shell {
+            return self.shell.handle_events(event);
+        }
+
+        return global_action.or_else(|| self.shell.handle_events(event));
+    }
+
+    fn update(&mut self, action: Action) -> Result<Option<Action>> {
+        match &action {
+            Action::Error(message) => self
+                .overlays
+                .push(Overlay::Error(ErrorBox::new("Error", message))),
+            Action::OpenOverlay(overlay) => self.overlays.push(overlay.clone()),
+            Action::CloseOverlay => {
+                self.overlays.pop();
             }
             _ => {}
         }
 
-        Ok(())
+        self.shell.update(action)
     }
 
-    fn quit(&mut self) {
-        self.status = AppStatus::Quit;
+    fn draw(&mut self, frame: &mut Frame, area: Rect) {
+        self.shell.draw(frame, area);
+        self.overlays.draw(frame, area, &self.theme);
     }
 }
 
@@ -69,102 +106,257 @@ impl<TShell: Widget + IAppWidget + Clone> App<TShell> {
 mod app_tests {
     use super::App;
 
-    use crate::{
-        app_status::AppStatus,
-        consts::{QUIT_KEY_CTRL_C, QUIT_KEY_CTRL_D},
-        shared::{
-            config::Config,
-            events::{Event, EventLoopHandler},
-            tui::{Tui, TuiRunner},
-        },
-        shell::IAppWidget,
+    use std::collections::BTreeMap;
+
+    use crate::shared::{
+        actions::Action,
+        component::Component,
+        config::Config,
+        events::Event,
+        focus::Pane,
+        keymap::{KeyContext, Keymap},
+        overlay::{ConfirmDialog, ErrorBox, Overlay, TextPrompt},
     };
 
     use color_eyre::eyre::Result;
     use crossterm::event::{Event as CrosstermEvent, KeyCode, KeyEvent, KeyModifiers};
     use pretty_assertions::assert_eq;
-    use ratatui::{buffer::Buffer, layout::Rect, widgets::Widget};
+    use ratatui::{backend::TestBackend, layout::Rect, Frame, Terminal};
     use test_case::test_case;
 
     const OTHER_KEY: KeyEvent = KeyEvent::new(KeyCode::Char('a'), KeyModifiers::empty());
 
-    #[derive(Default, Clone)]
+    fn key_event(code: KeyCode, modifiers: KeyModifiers) -> Event {
+        return Event::Crossterm(CrosstermEvent::Key(KeyEvent::new(code, modifiers)));
+    }
+
+    #[derive(Default)]
     struct TestShell {
+        handled_event: bool,
+        is_drawn: bool,
+        key_context: Option<KeyContext>,
         ran_init: bool,
+        updated_with: Option<Action>,
     }
 
-    impl IAppWidget for TestShell {
+    impl Component for TestShell {
+        fn key_context(&self) -> Option<KeyContext> {
+            return self.key_context;
+        }
+
+        fn handle_events(&mut self, _event: Event) -> Option<Action> {
+            self.handled_event = true;
+            None
+        }
+
         fn init(&mut self) -> Result<()> {
             self.ran_init = true;
             Ok(())
         }
+
+        fn update(&mut self, action: Action) -> Result<Option<Action>> {
+            self.updated_with = Some(action);
+            Ok(None)
+        }
+
+        fn draw(&mut self, _frame: &mut Frame, _area: Rect) {
+            self.is_drawn = true;
+        }
     }
 
-    impl Widget for TestShell {
-        fn render(self, _area: Rect, _buf: &mut Buffer) {}
+    #[test_case(key_event(KeyCode::Char('c'), KeyModifiers::CONTROL), Some(Action::Quit), "App should have terminated."; "Ctrl + C")]
+    #[test_case(key_event(KeyCode::Char('d'), KeyModifiers::CONTROL), Some(Action::Quit), "App should have terminated."; "Ctrl + D")]
+    #[test_case(Event::Crossterm(CrosstermEvent::Key(OTHER_KEY)), None, "App should not have terminated."; "Any Other Key Should Not Quit")]
+    #[test_case(key_event(KeyCode::Char('z'), KeyModifiers::CONTROL), Some(Action::Suspend), "App should have suspended."; "Ctrl + Z")]
+    #[test_case(key_event(KeyCode::Char('?'), KeyModifiers::SHIFT), Some(Action::Help), "App should have opened the help."; "Question Mark")]
+    #[test_case(key_event(KeyCode::Tab, KeyModifiers::empty()), Some(Action::FocusNext), "App should have focused the next pane."; "Tab")]
+    #[test_case(key_event(KeyCode::BackTab, KeyModifiers::SHIFT), Some(Action::FocusPrev), "App should have focused the previous pane."; "Shift + Tab")]
+    #[test_case(key_event(KeyCode::Char('0'), KeyModifiers::empty()), Some(Action::Focus(Pane::Main)), "App should have focused the main pane."; "0")]
+    #[test_case(key_event(KeyCode::Char('2'), KeyModifiers::empty()), Some(Action::Focus(Pane::Projects)), "App should have focused the projects pane."; "2")]
+    #[test_case(key_event(KeyCode::Char('9'), KeyModifiers::empty()), None, "No pane should have been focused."; "Unknown Pane Number")]
+    fn test_global_key_events(event: Event, expected: Option<Action>, failure_message: &str) {
+        let mut app = App::<TestShell>::default();
+
+        let response = app.handle_events(event);
+
+        assert_eq!(response, expected, "{}", failure_message);
     }
 
-    fn setup() -> Result<(App<TestShell>, Tui, EventLoopHandler)> {
+    #[test]
+    fn test_configured_keybindings() -> Result<()> {
         let mut app = App::<TestShell>::default();
-        app.status = AppStatus::Running;
-        app.shell.ran_init = false;
+        let keybindings = BTreeMap::from([(
+            KeyContext::Global,
+            BTreeMap::from([(String::from("<Ctrl-c>"), String::from("Help"))]),
+        )]);
+        app.register_config_handler(Config {
+            keymap: Keymap::default().with_keybindings(&keybindings)?,
+            ..Config::default()
+        })?;
 
-        let mut tui = TuiRunner::default();
-        tui.set_draw(false);
-        let backend = tui.init()?;
+        let response = app.handle_events(key_event(KeyCode::Char('c'), KeyModifiers::CONTROL));
+        assert_eq!(response, Some(Action::Help), "Ctrl-c should show the help.");
 
-        const FPS: f64 = 30.0;
-        let event_loop = EventLoopHandler::new(tokio_stream::empty(), FPS);
+        let response = app.handle_events(key_event(KeyCode::Char('d'), KeyModifiers::CONTROL));
+        assert_eq!(response, Some(Action::Quit), "Ctrl-d should still quit.");
 
-        return Ok((app, backend, event_loop));
+        Ok(())
     }
 
-    #[test_case(Event::Crossterm(CrosstermEvent::Key(QUIT_KEY_CTRL_C)), AppStatus::Quit, "App should have terminated."; "Ctrl + C")]
-    #[test_case(Event::Crossterm(CrosstermEvent::Key(QUIT_KEY_CTRL_D)), AppStatus::Quit, "App should have terminated."; "Ctrl + D")]
-    #[test_case(Event::Crossterm(CrosstermEvent::Key(OTHER_KEY)), AppStatus::Running, "App should not have terminated."; "Any Other Key Should Not Quit")]
-    #[tokio::test]
-    async fn test_should_quit_events(
-        event: Event,
-        expected: AppStatus,
-        failure_message: &str,
-    ) -> Result<()> {
-        let (mut app, mut backend, _) = setup()?;
+    #[test]
+    fn test_pane_bindings_take_precedence() -> Result<()> {
+        let mut app = App::<TestShell>::default();
+        let keybindings = BTreeMap::from([(
+            KeyContext::Main,
+            BTreeMap::from([(String::from("?"), String::from("ScrollDown"))]),
+        )]);
+        app.register_config_handler(Config {
+            keymap: Keymap::default().with_keybindings(&keybindings)?,
+            ..Config::default()
+        })?;
+        app.shell.key_context = Some(KeyContext::Main);
 
-        app.event_handler(event, &mut backend)?;
+        let response = app.handle_events(key_event(KeyCode::Char('?'), KeyModifiers::empty()));
+        assert_eq!(response, None, "? should not show the help.");
+        assert!(app.shell.handled_event, "The pane should handle ?.");
 
-        assert_eq!(app.status, expected, "{}", failure_message);
+        app.shell.key_context = Some(KeyContext::Projects);
+        let response = app.handle_events(key_event(KeyCode::Char('?'), KeyModifiers::empty()));
+        assert_eq!(
+            response,
+            Some(Action::Help),
+            "Other panes should show the help."
+        );
 
         Ok(())
     }
 
-    // TODO: Can create passable test once switched over to StatefulWidgets,
-    //-      where `is_rendered` can be used for assertions.
-    // #[tokio::test]
-    // async fn test_render_event_handler() -> Result<()> {
-    //     let (mut app, mut backend, _) = setup()?;
+    #[test]
+    fn test_draw_shell() -> Result<()> {
+        let mut app = App::<TestShell>::default();
+        let mut terminal = Terminal::new(TestBackend::new(30, 3))?;
 
-    //     app.event_handler(Ok(Event::Render), &mut backend)?;
+        terminal.draw(|frame| app.draw(frame, frame.area()))?;
 
-    //     assert_eq!(
-    //         app.shell.is_drawn, true,
-    //         "App should have rendered view in terminal."
-    //     );
+        assert!(
+            app.shell.is_drawn,
+            "App should have rendered view in terminal."
+        );
 
-    //     Ok(())
-    // }
+        Ok(())
+    }
 
-    #[tokio::test]
-    async fn test_run_shell_init() -> Result<()> {
-        let (mut app, backend, event_loop) = setup()?;
-        app.status = AppStatus::Quit;
+    #[test]
+    fn test_init_shell() -> Result<()> {
+        let mut app = App::<TestShell>::default();
 
-        app.run(backend, Config::default(), event_loop)?;
+        app.init()?;
 
-        assert_eq!(
-            app.shell.ran_init, true,
+        assert!(
+            app.shell.ran_init,
             "App should have called shell's init() method."
         );
 
         Ok(())
     }
+
+    #[test]
+    fn test_error_is_shown_until_key_press() -> Result<()> {
+        let mut app = App::<TestShell>::default();
+        let error = String::from("Something went wrong");
+
+        app.update(Action::Error(error.clone()))?;
+        assert_eq!(
+            app.overlays.top(),
+            Some(&Overlay::Error(ErrorBox::new("Error", &error))),
+            "App should be showing the error."
+        );
+
+        let response = app.handle_events(Event::Crossterm(CrosstermEvent::Key(OTHER_KEY)));
+        assert_eq!(response, None, "Dismissing the error should not act.");
+        assert!(
+            app.overlays.is_empty(),
+            "App should have dismissed the error."
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_overlay_captures_input() -> Result<()> {
+        let mut app = App::<TestShell>::default();
+        let dialog = ConfirmDialog::new("Quit", "Quit lazynx?", Action::Quit);
+
+        app.update(Action::OpenOverlay(Overlay::Confirm(dialog)))?;
+        let response = app.handle_events(Event::Crossterm(CrosstermEvent::Key(KeyEvent::new(
+            KeyCode::Char('y'),
+            KeyModifiers::empty(),
+        ))));
+
+        assert_eq!(response, Some(Action::Quit));
+        assert!(
+            !app.shell.handled_event,
+            "The shell should not receive input while an overlay is open."
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_typed_quit_key_goes_to_the_prompt() -> Result<()> {
+        let mut app = App::<TestShell>::default();
+        let keybindings = BTreeMap::from([(
+            KeyContext::Global,
+            BTreeMap::from([(String::from("q"), String::from("Quit"))]),
+        )]);
+        app.register_config_handler(Config {
+            keymap: Keymap::default().with_keybindings(&keybindings)?,
+            ..Config::default()
+        })?;
+        app.update(Action::OpenOverlay(Overlay::Prompt(TextPrompt::new(
+            "filter", "Filter",
+        ))))?;
+
+        let response = app.handle_events(key_event(KeyCode::Char('q'), KeyModifiers::empty()));
+        assert_eq!(response, None, "q should not quit.");
+        assert_eq!(
+            app.overlays.top(),
+            Some(&Overlay::Prompt(
+                TextPrompt::new("filter", "Filter").with_input("q")
+            )),
+            "q should have been typed into the prompt."
+        );
+
+        let response = app.handle_events(key_event(KeyCode::Char('c'), KeyModifiers::CONTROL));
+        assert_eq!(response, Some(Action::Quit), "Ctrl-c should still quit.");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_close_overlay() -> Result<()> {
+        let mut app = App::<TestShell>::default();
+        app.update(Action::Error(String::from("Oops")))?;
+
+        app.update(Action::CloseOverlay)?;
+
+        assert!(app.overlays.is_empty(), "The overlay should have closed.");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_update_shell() -> Result<()> {
+        let mut app = App::<TestShell>::default();
+
+        app.update(Action::Help)?;
+
+        assert_eq!(
+            app.shell.updated_with,
+            Some(Action::Help),
+            "App should have forwarded the action to the shell."
+        );
+
+        Ok(())
+    }
 }
@@ -1,6 +0,0 @@
-#[derive(Debug, Default, Eq, PartialEq)]
-pub enum AppStatus {
-    #[default]
-    Running,
-    Quit,
-}
@@ -0,0 +1,44 @@
+mod cli;
+pub use cli::Cli;
+
+mod command;
+pub use command::Command;
+
+mod completions_command;
+pub use completions_command::{CompletionsCommand, COMPLETE_VAR};
+
+mod config_command;
+pub use config_command::ConfigCommand;
+
+mod config_files;
+pub use config_files::ConfigFiles;
+
+mod doctor_command;
+pub use doctor_command::DoctorCommand;
+
+mod format_table;
+use format_table::format_table;
+
+mod info_command;
+pub use info_command::InfoCommand;
+
+mod loaded_workspace;
+pub use loaded_workspace::LoadedWorkspace;
+
+mod print_output;
+use print_output::{print_output, write_output};
+
+mod projects_command;
+pub use projects_command::ProjectsCommand;
+
+mod run_command;
+pub use run_command::RunCommand;
+
+mod targets_command;
+pub use targets_command::TargetsCommand;
+
+mod task;
+use task::Task;
+
+mod workspace_completions;
+use workspace_completions::{complete_configurations, complete_projects, complete_tasks};
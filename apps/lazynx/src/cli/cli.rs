@@ -0,0 +1,272 @@
+use std::{
+    env,
+    path::{self, PathBuf},
+};
+
+use clap::Parser;
+use clap_complete::ArgValueCompleter;
+use color_eyre::eyre::{Result, WrapErr};
+use config::{ConfigError, Map, Source, Value};
+use tracing::level_filters::LevelFilter;
+
+use super::{complete_projects, complete_tasks, Command, Task};
+use crate::shared::{actions::Action, focus::Pane};
+
+/// The version, followed by the commit and the date it was built from.
+const VERSION: &str = concat!(
+    env!("CARGO_PKG_VERSION"),
+    " (",
+    env!("LAZYNX_GIT_SHA"),
+    " ",
+    env!("LAZYNX_BUILD_DATE"),
+    ")"
+);
+
+/// The command line interface.
+///
+/// Every flag that is set overrides the matching setting from the config file
+/// and the environment.
+#[derive(Parser, Clone, Debug, Default)]
+#[command(version = VERSION, about, long_about = None)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    /// Start with the project selected, e.g. `api`. Given a target too, e.g.
+    /// `api:build`, the target is run right away.
+    #[arg(
+        value_name = "PROJECT[:TARGET]",
+        add = ArgValueCompleter::new(complete_tasks)
+    )]
+    pub task: Option<String>,
+
+    /// Start with the project selected.
+    #[arg(
+        long,
+        value_name = "NAME",
+        conflicts_with = "task",
+        add = ArgValueCompleter::new(complete_projects)
+    )]
+    pub project: Option<String>,
+
+    /// Start with the pane focused.
+    #[arg(long, value_name = "PANE")]
+    pub view: Option<Pane>,
+
+    /// Path to the config file to load.
+    #[arg(short, long, value_name = "FILE")]
+    pub config: Option<PathBuf>,
+
+    /// The Nx workspace to open, instead of the one in the working directory.
+    #[arg(short, long, value_name = "DIR")]
+    pub workspace: Option<PathBuf>,
+
+    /// The directory to store application data (logs, sessions etc.) in.
+    #[arg(long, value_name = "DIR")]
+    pub data_dir: Option<PathBuf>,
+
+    /// Frames rendered per second.
+    #[arg(short, long, value_name = "FLOAT")]
+    pub frame_rate: Option<f64>,
+
+    /// Ticks per second, used for updating state like spinners and timers.
+    #[arg(short, long, value_name = "FLOAT")]
+    pub tick_rate: Option<f64>,
+
+    /// The log level to use: error, warn, info, debug, trace or off.
+    #[arg(short, long, value_name = "LEVEL")]
+    pub log_level: Option<LevelFilter>,
+
+    /// Enable mouse support.
+    #[arg(long, overrides_with = "no_mouse")]
+    pub mouse: bool,
+
+    /// Disable mouse support.
+    #[arg(long, overrides_with = "mouse")]
+    pub no_mouse: bool,
+
+    /// Enable pasting from the clipboard.
+    #[arg(long)]
+    pub paste: bool,
+}
+
+impl Cli {
+    /// Makes the workspace passed with `--workspace` the working directory, so
+    /// it is opened just like when lazynx is started inside it. Relative paths
+    /// passed with other flags still point where they did.
+    pub fn enter_workspace(&mut self) -> Result<()> {
+        let Some(workspace) = &self.workspace else {
+            return Ok(());
+        };
+
+        for path in [&mut self.config, &mut self.data_dir].into_iter().flatten() {
+            *path = path::absolute(&*path)?;
+        }
+
+        env::set_current_dir(workspace)
+            .wrap_err_with(|| format!("Unable to open the workspace {}", workspace.display()))?;
+
+        Ok(())
+    }
+}
+
+impl Cli {
+    /// The action that opens the TUI where `--project`, `--view` or the task
+    /// point to, if any of them were given.
+    pub fn open_link(&self) -> Result<Option<Action>> {
+        let task = match (&self.task, &self.project) {
+            (Some(task), _) => Some(Task::parse(task)?),
+            (None, Some(project)) => Some(Task {
+                project: project.clone(),
+                target: None,
+                configuration: None,
+            }),
+            (None, None) => None,
+        };
+
+        if task.is_none() && self.view.is_none() {
+            return Ok(None);
+        }
+
+        return Ok(Some(Action::OpenLink {
+            project: task.as_ref().map(|task| task.project.clone()),
+            pane: self.view,
+            target: task.as_ref().and_then(|task| task.target.clone()),
+            configuration: task.and_then(|task| task.configuration),
+        }));
+    }
+}
+
+impl Source for Cli {
+    fn clone_into_box(&self) -> Box<dyn Source + Send + Sync> {
+        return Box::new(self.clone());
+    }
+
+    fn collect(&self) -> Result<Map<String, Value>, ConfigError> {
+        let mut overrides = Map::new();
+
+        if let Some(frame_rate) = self.frame_rate {
+            overrides.insert(String::from("frame_rate"), Value::from(frame_rate));
+        }
+        if let Some(tick_rate) = self.tick_rate {
+            overrides.insert(String::from("tick_rate"), Value::from(tick_rate));
+        }
+        if let Some(log_level) = self.log_level {
+            overrides.insert(
+                String::from("log_level"),
+                Value::from(log_level.to_string()),
+            );
+        }
+        if let Some(data_dir) = &self.data_dir {
+            overrides.insert(
+                String::from("data_dir"),
+                Value::from(data_dir.to_string_lossy().into_owned()),
+            );
+        }
+        if self.mouse || self.no_mouse {
+            overrides.insert(String::from("enable_mouse"), Value::from(self.mouse));
+        }
+        if self.paste {
+            overrides.insert(String::from("enable_paste"), Value::from(true));
+        }
+
+        return Ok(overrides);
+    }
+}
+
+#[cfg(test)]
+mod cli_tests {
+    use super::Cli;
+
+    use clap::{CommandFactory, Parser};
+    use color_eyre::eyre::Result;
+    use config::{Source, Value};
+    use pretty_assertions::assert_eq;
+    use test_case::test_case;
+
+    use crate::shared::{actions::Action, focus::Pane};
+
+    #[test]
+    fn test_only_set_flags_override() -> Result<()> {
+        let cli = Cli::try_parse_from(["lazynx", "--frame-rate", "60", "--log-level", "debug"])?;
+
+        let overrides = cli.collect()?;
+
+        assert_eq!(overrides.len(), 2);
+        assert_eq!(overrides["frame_rate"], Value::from(60.0));
+        assert_eq!(overrides["log_level"], Value::from("debug"));
+
+        Ok(())
+    }
+
+    #[test_case(&["--mouse"], Some(true); "Mouse")]
+    #[test_case(&["--no-mouse"], Some(false); "No Mouse")]
+    #[test_case(&["--mouse", "--no-mouse"], Some(false); "Last Flag Wins")]
+    #[test_case(&[], None; "Neither")]
+    fn test_mouse_flags(flags: &[&str], expected: Option<bool>) -> Result<()> {
+        let cli = Cli::try_parse_from(["lazynx"].iter().chain(flags))?;
+
+        let overrides = cli.collect()?;
+
+        assert_eq!(
+            overrides.get("enable_mouse").cloned(),
+            expected.map(Value::from)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_version_includes_commit_and_build_date() {
+        let version = Cli::command().render_version();
+
+        assert!(
+            version.contains(env!("LAZYNX_GIT_SHA")) && version.contains(env!("LAZYNX_BUILD_DATE")),
+            "{version} should name the commit and build date."
+        );
+    }
+
+    #[test_case(&["api:build", "--view", "workspace"], Some("api"), Some(Pane::Workspace), Some("build"); "Task And View")]
+    #[test_case(&["--project", "api"], Some("api"), None, None; "Project")]
+    #[test_case(&["--view", "output"], None, Some(Pane::Main), None; "View Alias")]
+    #[test_case(&["--project", "api", "--view", "targets"], Some("api"), Some(Pane::Targets), None; "Project Targets")]
+    fn test_open_link(
+        args: &[&str],
+        project: Option<&str>,
+        pane: Option<Pane>,
+        target: Option<&str>,
+    ) -> Result<()> {
+        let cli = Cli::try_parse_from(["lazynx"].iter().chain(args))?;
+
+        assert_eq!(
+            cli.open_link()?,
+            Some(Action::OpenLink {
+                project: project.map(str::to_owned),
+                pane,
+                target: target.map(str::to_owned),
+                configuration: None,
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_subcommands_are_not_taken_for_projects() -> Result<()> {
+        let cli = Cli::try_parse_from(["lazynx", "projects"])?;
+
+        assert!(cli.command.is_some() && cli.task.is_none());
+        assert_eq!(cli.open_link()?, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_no_flags() -> Result<()> {
+        let cli = Cli::try_parse_from(["lazynx"])?;
+
+        assert!(cli.collect()?.is_empty(), "Nothing should be overridden.");
+
+        Ok(())
+    }
+}
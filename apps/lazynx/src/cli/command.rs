@@ -0,0 +1,47 @@
+use clap::Subcommand;
+use color_eyre::eyre::Result;
+
+use super::{
+    Cli, CompletionsCommand, ConfigCommand, DoctorCommand, InfoCommand, ProjectsCommand,
+    RunCommand, TargetsCommand,
+};
+
+/// Commands that run instead of the TUI.
+#[derive(Subcommand, Clone, Debug)]
+pub enum Command {
+    /// Manage the config file.
+    #[command(subcommand)]
+    Config(ConfigCommand),
+
+    /// List the projects of the workspace.
+    Projects(ProjectsCommand),
+
+    /// List the targets of a project.
+    Targets(TargetsCommand),
+
+    /// Summarize the workspace.
+    Info(InfoCommand),
+
+    /// Run a target, print its output and exit with its status code.
+    Run(RunCommand),
+
+    /// Check the environment lazynx and Nx run in.
+    Doctor(DoctorCommand),
+
+    /// Print the shell completions, for bash, fish or zsh.
+    Completions(CompletionsCommand),
+}
+
+impl Command {
+    pub async fn run(&self, cli: &Cli) -> Result<()> {
+        return match self {
+            Command::Config(command) => command.run(cli),
+            Command::Projects(command) => command.run(cli),
+            Command::Targets(command) => command.run(cli),
+            Command::Info(command) => command.run(cli),
+            Command::Run(command) => command.run(cli).await,
+            Command::Doctor(command) => command.run(cli),
+            Command::Completions(command) => command.run(),
+        };
+    }
+}
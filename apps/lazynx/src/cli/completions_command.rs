@@ -0,0 +1,77 @@
+use clap::{Args, ValueEnum};
+use clap_complete::env::{Bash, EnvCompleter, Fish, Zsh};
+use color_eyre::eyre::Result;
+
+use super::print_output;
+
+/// The environment variable the completion scripts set when they ask lazynx
+/// for completions.
+pub const COMPLETE_VAR: &str = "LAZYNX_COMPLETE";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum CompletionShell {
+    Bash,
+    Fish,
+    Zsh,
+}
+
+impl CompletionShell {
+    fn completer(self) -> &'static dyn EnvCompleter {
+        return match self {
+            CompletionShell::Bash => &Bash,
+            CompletionShell::Fish => &Fish,
+            CompletionShell::Zsh => &Zsh,
+        };
+    }
+}
+
+#[derive(Args, Clone, Debug)]
+#[command(after_help = "Load them in your shell's startup file, e.g.:
+  bash: eval \"$(lazynx completions bash)\"
+  zsh:  source <(lazynx completions zsh)
+  fish: lazynx completions fish | source")]
+pub struct CompletionsCommand {
+    /// The shell to print the completions for.
+    pub shell: CompletionShell,
+}
+
+impl CompletionsCommand {
+    pub fn run(&self) -> Result<()> {
+        return print_output(&completion_script(self.shell)?);
+    }
+}
+
+/// The script that makes the shell ask lazynx for completions. They are
+/// worked out from the command line definition on every request, so the
+/// project and target names are always those of the current workspace.
+fn completion_script(shell: CompletionShell) -> Result<String> {
+    let bin = env!("CARGO_PKG_NAME");
+    let mut script = Vec::new();
+    shell
+        .completer()
+        .write_registration(COMPLETE_VAR, bin, bin, bin, &mut script)?;
+
+    return Ok(String::from_utf8(script)?);
+}
+
+#[cfg(test)]
+mod completions_command_tests {
+    use super::{completion_script, CompletionShell, COMPLETE_VAR};
+
+    use color_eyre::eyre::Result;
+    use test_case::test_case;
+
+    #[test_case(CompletionShell::Bash; "Bash")]
+    #[test_case(CompletionShell::Fish; "Fish")]
+    #[test_case(CompletionShell::Zsh; "Zsh")]
+    fn test_script_calls_back_into_lazynx(shell: CompletionShell) -> Result<()> {
+        let script = completion_script(shell)?;
+
+        assert!(
+            script.contains(COMPLETE_VAR) && script.contains("lazynx"),
+            "{script} should ask lazynx for completions."
+        );
+
+        Ok(())
+    }
+}
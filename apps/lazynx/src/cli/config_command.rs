@@ -0,0 +1,331 @@
+use std::{
+    env, fs,
+    io::{self, Write},
+    path::Path,
+    process,
+};
+
+use clap::Subcommand;
+use color_eyre::eyre::{bail, Result};
+use config::{File, Map, Source, Value, ValueKind};
+
+use super::{Cli, ConfigFiles};
+use crate::shared::config::{
+    default_config_template, environment_source, load_config, utils::default_data_dir,
+};
+
+#[derive(Subcommand, Clone, Debug)]
+pub enum ConfigCommand {
+    /// Print where the config files, data and logs are.
+    Path,
+
+    /// Write a config file documenting every setting.
+    Init {
+        /// Overwrite the config file if it already exists.
+        #[arg(long)]
+        force: bool,
+    },
+
+    /// Print the effective config and where each value came from.
+    Show,
+
+    /// Open the config file in $VISUAL or $EDITOR and validate it once saved.
+    Edit,
+}
+
+impl ConfigCommand {
+    pub fn run(&self, cli: &Cli) -> Result<()> {
+        let files = ConfigFiles::locate(cli)?;
+
+        return match self {
+            ConfigCommand::Path => {
+                print!("{}", format_paths(&files, cli));
+                Ok(())
+            }
+            ConfigCommand::Init { force } => {
+                init(&files.user, *force)?;
+                println!("Wrote {}", files.user.display());
+                Ok(())
+            }
+            ConfigCommand::Show => {
+                print!("{}", format_effective_config(&files, cli)?);
+                Ok(())
+            }
+            ConfigCommand::Edit => edit(&files, cli),
+        };
+    }
+}
+
+fn describe_file(path: &Path) -> String {
+    return match path.is_file() {
+        true => path.display().to_string(),
+        false => format!("{} (not created yet)", path.display()),
+    };
+}
+
+fn format_paths(files: &ConfigFiles, cli: &Cli) -> String {
+    let data_dir = load_config(&files.user, files.workspace.as_deref(), cli.clone())
+        .map(|loaded| loaded.config.data_dir)
+        .unwrap_or_else(|_| default_data_dir());
+    let log_file = data_dir.join(format!("{}.log", env!("CARGO_PKG_NAME")));
+    let workspace = match &files.workspace {
+        Some(path) => describe_file(path),
+        None => String::from("(not in an Nx workspace)"),
+    };
+
+    return [
+        format!("Config file:           {}", describe_file(&files.user)),
+        format!("Workspace config file: {workspace}"),
+        format!("Data directory:        {}", data_dir.display()),
+        format!("Log file:              {}", log_file.display()),
+    ]
+    .map(|line| line + "\n")
+    .concat();
+}
+
+fn init(config_file: &Path, force: bool) -> Result<()> {
+    if config_file
+        .extension()
+        .is_none_or(|extension| extension != "toml")
+    {
+        bail!(
+            "lazynx config init writes TOML, but {} is not a .toml file.",
+            config_file.display()
+        );
+    }
+    if config_file.exists() && !force {
+        bail!(
+            "{} already exists. Use --force to overwrite it.",
+            config_file.display()
+        );
+    }
+
+    if let Some(dir) = config_file.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(config_file, default_config_template()?)?;
+
+    Ok(())
+}
+
+/// Whether the setting, written as a dotted path, is set in the layer.
+fn is_set(layer: &Map<String, Value>, key: &str) -> bool {
+    let mut table = layer;
+    let mut parts = key.split('.').peekable();
+
+    while let Some(part) = parts.next() {
+        let Some(value) = table.get(part) else {
+            return false;
+        };
+        if parts.peek().is_none() {
+            return true;
+        }
+
+        let ValueKind::Table(nested) = &value.kind else {
+            return false;
+        };
+        table = nested;
+    }
+
+    return false;
+}
+
+/// Lists every setting as a dotted path with its value.
+fn flatten(prefix: &str, value: &serde_json::Value, settings: &mut Vec<(String, String)>) {
+    let serde_json::Value::Object(table) = value else {
+        settings.push((prefix.to_owned(), value.to_string()));
+        return;
+    };
+
+    for (key, value) in table {
+        let key = match prefix.is_empty() {
+            true => key.clone(),
+            false => format!("{prefix}.{key}"),
+        };
+        flatten(&key, value, settings);
+    }
+}
+
+fn format_effective_config(files: &ConfigFiles, cli: &Cli) -> Result<String> {
+    let loaded = load_config(&files.user, files.workspace.as_deref(), cli.clone())?;
+
+    let mut layers: Vec<(String, Map<String, Value>)> = vec![
+        (String::from("command line"), cli.collect()?),
+        (String::from("environment"), environment_source().collect()?),
+    ];
+    for path in [files.workspace.as_ref(), Some(&files.user)]
+        .into_iter()
+        .flatten()
+    {
+        let file = File::from(path.as_path()).required(false);
+        layers.push((path.display().to_string(), file.collect()?));
+    }
+
+    let mut settings = Vec::new();
+    flatten("", &serde_json::to_value(&loaded.config)?, &mut settings);
+
+    let mut output = String::new();
+    for (key, value) in &settings {
+        let origin = match layers.iter().find(|(_, layer)| is_set(layer, key)) {
+            Some((name, _)) if name == "environment" => {
+                format!("environment variable LAZYNX_{}", key.to_uppercase())
+            }
+            Some((name, _)) => name.clone(),
+            None => String::from("default"),
+        };
+        output.push_str(&format!("{key} = {value}  # {origin}\n"));
+    }
+
+    for warning in loaded.warnings {
+        output.push_str(&format!("# Warning: {warning}\n"));
+    }
+
+    return Ok(output);
+}
+
+fn confirm(question: &str) -> Result<bool> {
+    print!("{question} [Y/n] ");
+    io::stdout().flush()?;
+
+    let mut answer = String::new();
+    io::stdin().read_line(&mut answer)?;
+
+    return Ok(matches!(answer.trim(), "" | "y" | "Y" | "yes"));
+}
+
+/// Opens the user's config file in the editor until it is saved in a valid
+/// state, or the user gives up.
+fn edit(files: &ConfigFiles, cli: &Cli) -> Result<()> {
+    let config_file = &files.user;
+    if !config_file.exists() {
+        init(config_file, false)?;
+    }
+
+    let editor = env::var("VISUAL")
+        .or_else(|_| env::var("EDITOR"))
+        .unwrap_or_else(|_| String::from("vi"));
+
+    loop {
+        // Run through the shell, so editors given with arguments (e.g.
+        // `code --wait`) work.
+        let status = process::Command::new("sh")
+            .args(["-c", &format!("{editor} \"$1\""), "sh"])
+            .arg(config_file)
+            .status()?;
+        if !status.success() {
+            bail!("{editor} exited with {status}.");
+        }
+
+        match load_config(config_file, files.workspace.as_deref(), cli.clone()) {
+            Ok(loaded) => {
+                for warning in loaded.warnings {
+                    eprintln!("Warning: {warning}");
+                }
+                println!("{} is valid.", config_file.display());
+                return Ok(());
+            }
+            Err(error) => {
+                eprintln!("{error}");
+                if !confirm("Edit the config file again?")? {
+                    bail!("{} is invalid.", config_file.display());
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod config_command_tests {
+    use super::{format_effective_config, init};
+
+    use std::fs;
+
+    use clap::Parser;
+    use color_eyre::eyre::Result;
+    use pretty_assertions::assert_eq;
+    use test_utils::TempDir;
+
+    use crate::cli::{Cli, ConfigFiles};
+
+    #[test]
+    fn test_show_names_the_layer_of_each_value() -> Result<()> {
+        let dir = TempDir::new("config_command_show")?;
+        let user = dir.write("config.toml", "frame_rate = 60.0\nenable_mouse = true\n")?;
+        let workspace = dir.write(
+            ".lazynx.toml",
+            "enable_mouse = false\n[theme]\naccent = \"red\"\n",
+        )?;
+        let cli = Cli::try_parse_from(["lazynx", "--tick-rate", "10"])?;
+        let files = ConfigFiles {
+            user: user.clone(),
+            workspace: Some(workspace.clone()),
+        };
+
+        let output = format_effective_config(&files, &cli)?;
+        let line = |key: &str| {
+            output
+                .lines()
+                .find(|line| line.starts_with(&format!("{key} = ")))
+                .map(str::to_owned)
+        };
+
+        assert_eq!(
+            line("frame_rate"),
+            Some(format!("frame_rate = 60.0  # {}", user.display()))
+        );
+        assert_eq!(
+            line("enable_mouse"),
+            Some(format!("enable_mouse = false  # {}", workspace.display()))
+        );
+        assert_eq!(
+            line("theme.accent"),
+            Some(format!("theme.accent = \"Red\"  # {}", workspace.display()))
+        );
+        assert_eq!(
+            line("tick_rate"),
+            Some(String::from("tick_rate = 10.0  # command line"))
+        );
+        assert_eq!(
+            line("enable_paste"),
+            Some(String::from("enable_paste = false  # default"))
+        );
+        assert_eq!(
+            line("config_home"),
+            None,
+            "The config directory can't be set in the config."
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_init_keeps_existing_file() -> Result<()> {
+        let dir = TempDir::new("config_command_init")?;
+        let config_file = dir.join("nested").join("config.toml");
+
+        init(&config_file, false)?;
+        assert!(fs::read_to_string(&config_file)?.starts_with("# lazynx config file."));
+
+        fs::write(&config_file, "frame_rate = 60.0\n")?;
+        assert!(init(&config_file, false).is_err(), "Should not overwrite.");
+        assert_eq!(fs::read_to_string(&config_file)?, "frame_rate = 60.0\n");
+
+        init(&config_file, true)?;
+        assert!(fs::read_to_string(&config_file)?.starts_with("# lazynx config file."));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_init_only_writes_toml() -> Result<()> {
+        let dir = TempDir::new("config_command_init_yaml")?;
+
+        let error = init(&dir.join("config.yaml"), false)
+            .unwrap_err()
+            .to_string();
+
+        assert!(error.contains("writes TOML"), "{error}");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,34 @@
+use std::{env, path::PathBuf};
+
+use color_eyre::eyre::Result;
+
+use super::Cli;
+use crate::{
+    shared::config::utils::{default_config_dir, find_config_file, find_workspace_config_file},
+    workspace::find_workspace_root,
+};
+
+/// The user's config file and, inside an Nx workspace, the workspace's own.
+pub struct ConfigFiles {
+    pub user: PathBuf,
+    pub workspace: Option<PathBuf>,
+}
+
+impl ConfigFiles {
+    /// Finds the config files for the command line, i.e. the one passed with
+    /// `--config` or the one in the config directory, and the one at the root
+    /// of the Nx workspace containing the working directory.
+    pub fn locate(cli: &Cli) -> Result<Self> {
+        let user = match &cli.config {
+            Some(config_file) => config_file.clone(),
+            None => find_config_file(&default_config_dir())?,
+        };
+
+        let workspace = match find_workspace_root(&env::current_dir()?) {
+            Some(root) => Some(find_workspace_config_file(&root)?),
+            None => None,
+        };
+
+        return Ok(Self { user, workspace });
+    }
+}
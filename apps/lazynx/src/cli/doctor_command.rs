@@ -0,0 +1,65 @@
+use std::{
+    env,
+    io::{self, IsTerminal},
+    process,
+};
+
+use clap::{Args, CommandFactory};
+use color_eyre::eyre::Result;
+
+use super::{print_output, Cli, ConfigFiles};
+use crate::{
+    doctor::DoctorReport,
+    shared::{
+        config::{load_config, Config},
+        overlay::Check,
+    },
+};
+
+#[derive(Args, Clone, Debug)]
+pub struct DoctorCommand {}
+
+impl DoctorCommand {
+    /// Prints the report, exiting with 1 when any check failed.
+    pub fn run(&self, cli: &Cli) -> Result<()> {
+        let (config, config_check) = load_config_checked(cli);
+
+        let mut report =
+            DoctorReport::run(&config, &env::current_dir()?, io::stdin().is_terminal());
+        report.checks.insert(0, config_check);
+
+        print_output(&format!(
+            "lazynx {} on {} {}\n\n{}",
+            Cli::command().get_version().unwrap_or_default(),
+            env::consts::OS,
+            env::consts::ARCH,
+            report.format()
+        ))?;
+
+        if report.has_failures() {
+            process::exit(1);
+        }
+
+        Ok(())
+    }
+}
+
+/// Loads the config like the TUI does, falling back to the defaults so the
+/// other checks still run when it is broken.
+fn load_config_checked(cli: &Cli) -> (Config, Check) {
+    let loaded = ConfigFiles::locate(cli).and_then(|files| {
+        let loaded = load_config(&files.user, files.workspace.as_deref(), cli.clone())?;
+        return Ok((files, loaded));
+    });
+
+    return match loaded {
+        Ok((files, loaded)) => {
+            let check = match loaded.warnings.first() {
+                Some(warning) => Check::warn("Config", warning),
+                None => Check::pass("Config", &files.user.display().to_string()),
+            };
+            (loaded.config, check)
+        }
+        Err(error) => (Config::default(), Check::fail("Config", &error.to_string())),
+    };
+}
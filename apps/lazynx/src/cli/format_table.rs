@@ -0,0 +1,49 @@
+/// Lines the rows up in columns, separated by two spaces.
+pub fn format_table(rows: &[Vec<String>]) -> String {
+    let column_count = rows.iter().map(Vec::len).max().unwrap_or_default();
+    let widths: Vec<usize> = (0..column_count)
+        .map(|column| {
+            rows.iter()
+                .filter_map(|row| row.get(column))
+                .map(|cell| cell.chars().count())
+                .max()
+                .unwrap_or_default()
+        })
+        .collect();
+
+    let mut output = String::new();
+    for row in rows {
+        let line = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect::<Vec<_>>()
+            .join("  ");
+
+        output.push_str(line.trim_end());
+        output.push('\n');
+    }
+
+    return output;
+}
+
+#[cfg(test)]
+mod format_table_tests {
+    use super::format_table;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_columns_are_aligned() {
+        let rows = [
+            vec![String::from("NAME"), String::from("TYPE")],
+            vec![String::from("shared-ui"), String::from("lib")],
+            vec![String::from("api"), String::new()],
+        ];
+
+        assert_eq!(
+            format_table(&rows),
+            "NAME       TYPE\nshared-ui  lib\napi\n"
+        );
+    }
+}
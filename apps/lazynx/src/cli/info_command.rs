@@ -0,0 +1,144 @@
+use std::path::PathBuf;
+
+use clap::{Args, CommandFactory};
+use color_eyre::eyre::Result;
+use serde::Serialize;
+
+use super::{print_output, Cli, LoadedWorkspace};
+use crate::workspace::{find_nx_version, ProjectType};
+
+#[derive(Args, Clone, Debug)]
+pub struct InfoCommand {
+    /// Print the summary as JSON.
+    #[arg(long)]
+    pub json: bool,
+}
+
+/// A summary of the workspace and the setup lazynx runs with.
+#[derive(Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct WorkspaceInfo {
+    applications: usize,
+    config_file: PathBuf,
+    lazynx_version: String,
+    libraries: usize,
+    nx_version: Option<String>,
+    projects: usize,
+    root: PathBuf,
+    targets: usize,
+    workspace_config_file: Option<PathBuf>,
+}
+
+impl InfoCommand {
+    pub fn run(&self, cli: &Cli) -> Result<()> {
+        let info = collect_info(&LoadedWorkspace::load(cli)?);
+
+        let output = match self.json {
+            true => serde_json::to_string_pretty(&info)? + "\n",
+            false => format_info(&info),
+        };
+
+        return print_output(&output);
+    }
+}
+
+fn collect_info(workspace: &LoadedWorkspace) -> WorkspaceInfo {
+    let count_type = |project_type| {
+        workspace
+            .projects
+            .iter()
+            .filter(|project| project.project_type == Some(project_type))
+            .count()
+    };
+
+    return WorkspaceInfo {
+        applications: count_type(ProjectType::Application),
+        config_file: workspace.config_files.user.clone(),
+        lazynx_version: Cli::command().get_version().unwrap_or_default().to_owned(),
+        libraries: count_type(ProjectType::Library),
+        nx_version: find_nx_version(&workspace.root),
+        projects: workspace.projects.len(),
+        root: workspace.root.clone(),
+        targets: workspace
+            .projects
+            .iter()
+            .map(|project| project.targets.len())
+            .sum(),
+        workspace_config_file: workspace.config_files.workspace.clone(),
+    };
+}
+
+fn format_info(info: &WorkspaceInfo) -> String {
+    let display = |path: &Option<PathBuf>| match path {
+        Some(path) => path.display().to_string(),
+        None => String::from("-"),
+    };
+
+    return [
+        format!("Workspace:              {}", info.root.display()),
+        format!(
+            "Nx version:             {}",
+            info.nx_version.as_deref().unwrap_or("unknown")
+        ),
+        format!(
+            "Projects:               {} (applications: {}, libraries: {})",
+            info.projects, info.applications, info.libraries
+        ),
+        format!("Targets:                {}", info.targets),
+        format!("Config file:            {}", info.config_file.display()),
+        format!(
+            "Workspace config file:  {}",
+            display(&info.workspace_config_file)
+        ),
+        format!("lazynx version:         {}", info.lazynx_version),
+    ]
+    .map(|line| line + "\n")
+    .concat();
+}
+
+#[cfg(test)]
+mod info_command_tests {
+    use super::collect_info;
+
+    use color_eyre::eyre::Result;
+    use pretty_assertions::assert_eq;
+
+    use crate::{
+        cli::{ConfigFiles, LoadedWorkspace},
+        shared::config::Config,
+        workspace::{load_projects, test_bed::MockNxWorkspace},
+    };
+
+    #[test]
+    fn test_collect_info() -> Result<()> {
+        let mock = MockNxWorkspace::new("info_command_collect")?;
+        mock.write(
+            "package.json",
+            r#"{ "devDependencies": { "nx": "20.0.6" } }"#,
+        )?;
+        let workspace = LoadedWorkspace {
+            config: Config::default(),
+            config_files: ConfigFiles {
+                user: mock.root.join("config.toml"),
+                workspace: None,
+            },
+            projects: load_projects(&mock.root)?,
+            root: mock.root.clone(),
+        };
+
+        let info = collect_info(&workspace);
+
+        assert_eq!(info.nx_version.as_deref(), Some("20.0.6"));
+        assert_eq!(
+            (
+                info.projects,
+                info.applications,
+                info.libraries,
+                info.targets
+            ),
+            (2, 1, 1, 4)
+        );
+
+        Ok(())
+    }
+}
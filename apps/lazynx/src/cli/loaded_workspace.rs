@@ -0,0 +1,101 @@
+use std::{env, path::PathBuf};
+
+use color_eyre::eyre::{bail, Result};
+
+use super::{Cli, ConfigFiles};
+use crate::{
+    shared::config::{load_config, Config, LoadedConfig},
+    workspace::{find_workspace_root, load_projects, Project, WorkspaceCache},
+};
+
+/// The Nx workspace containing the working directory, with the projects the
+/// TUI would list.
+pub struct LoadedWorkspace {
+    pub config: Config,
+    pub config_files: ConfigFiles,
+    pub projects: Vec<Project>,
+    pub root: PathBuf,
+}
+
+impl LoadedWorkspace {
+    /// Loads the workspace, leaving out the projects hidden in the config.
+    pub fn load(cli: &Cli) -> Result<Self> {
+        let (root, config_files, loaded) = Self::load_config(cli)?;
+        for warning in &loaded.warnings {
+            eprintln!("Warning: {warning}");
+        }
+
+        let projects = load_projects(&root)?;
+        let cache = WorkspaceCache::new(&loaded.config.data_dir, &root);
+        if let Err(error) = cache.save(&projects) {
+            eprintln!("Warning: Unable to cache the projects: {error}");
+        }
+
+        return Ok(Self::new(root, config_files, loaded.config, projects));
+    }
+
+    /// Like [`LoadedWorkspace::load`], but takes the projects from the cache
+    /// when there is one and prints no warnings, since shell completions have
+    /// to be instant and quiet.
+    pub fn load_cached(cli: &Cli) -> Result<Self> {
+        let (root, config_files, loaded) = Self::load_config(cli)?;
+        let cache = WorkspaceCache::new(&loaded.config.data_dir, &root);
+
+        let projects = match cache.load() {
+            Some(projects) => projects,
+            None => {
+                let projects = load_projects(&root)?;
+                let _ = cache.save(&projects);
+                projects
+            }
+        };
+
+        return Ok(Self::new(root, config_files, loaded.config, projects));
+    }
+
+    fn load_config(cli: &Cli) -> Result<(PathBuf, ConfigFiles, LoadedConfig)> {
+        let current_dir = env::current_dir()?;
+        let Some(root) = find_workspace_root(&current_dir) else {
+            bail!("{} is not inside an Nx workspace.", current_dir.display());
+        };
+
+        let config_files = ConfigFiles::locate(cli)?;
+        let loaded = load_config(
+            &config_files.user,
+            config_files.workspace.as_deref(),
+            cli.clone(),
+        )?;
+
+        return Ok((root, config_files, loaded));
+    }
+
+    fn new(
+        root: PathBuf,
+        config_files: ConfigFiles,
+        config: Config,
+        projects: Vec<Project>,
+    ) -> Self {
+        let projects = projects
+            .into_iter()
+            .filter(|project| !config.hidden_projects.contains(&project.name))
+            .collect();
+
+        return Self {
+            config,
+            config_files,
+            projects,
+            root,
+        };
+    }
+
+    pub fn find_project(&self, name: &str) -> Result<&Project> {
+        let Some(project) = self.projects.iter().find(|project| project.name == name) else {
+            bail!(
+                "There is no project named `{name}` in {}.",
+                self.root.display()
+            );
+        };
+
+        return Ok(project);
+    }
+}
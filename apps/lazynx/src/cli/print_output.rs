@@ -0,0 +1,17 @@
+use std::io::{self, ErrorKind, Write};
+
+use color_eyre::eyre::Result;
+
+/// Writes the output to stdout. A reader that stops early, like `head`, is not
+/// an error.
+pub fn print_output(output: &str) -> Result<()> {
+    return write_output(&mut io::stdout().lock(), output);
+}
+
+/// Writes the output, ignoring a reader that stops early.
+pub fn write_output(out: &mut impl Write, output: &str) -> Result<()> {
+    return match out.write_all(output.as_bytes()) {
+        Err(error) if error.kind() != ErrorKind::BrokenPipe => Err(error.into()),
+        _ => Ok(()),
+    };
+}
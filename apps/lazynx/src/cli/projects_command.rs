@@ -0,0 +1,121 @@
+use clap::Args;
+use color_eyre::eyre::Result;
+
+use super::{format_table, print_output, Cli, LoadedWorkspace};
+use crate::workspace::{Project, ProjectType};
+
+#[derive(Args, Clone, Debug)]
+pub struct ProjectsCommand {
+    /// Print the projects as JSON.
+    #[arg(long)]
+    pub json: bool,
+
+    /// Only list applications or libraries.
+    #[arg(long = "type", value_name = "TYPE")]
+    pub project_type: Option<ProjectType>,
+
+    /// Only list projects with the tag. Can be given more than once.
+    #[arg(long = "tag", value_name = "TAG")]
+    pub tags: Vec<String>,
+}
+
+impl ProjectsCommand {
+    pub fn run(&self, cli: &Cli) -> Result<()> {
+        let workspace = LoadedWorkspace::load(cli)?;
+        let projects = self.filter(&workspace.projects);
+
+        let output = match self.json {
+            true => serde_json::to_string_pretty(&projects)? + "\n",
+            false => format_projects(&projects),
+        };
+
+        return print_output(&output);
+    }
+
+    fn filter<'a>(&self, projects: &'a [Project]) -> Vec<&'a Project> {
+        return projects
+            .iter()
+            .filter(|project| {
+                self.project_type
+                    .is_none_or(|project_type| project.project_type == Some(project_type))
+            })
+            .filter(|project| self.tags.iter().all(|tag| project.tags.contains(tag)))
+            .collect();
+    }
+}
+
+fn format_projects(projects: &[&Project]) -> String {
+    let header = ["NAME", "TYPE", "ROOT", "TAGS"].map(String::from).to_vec();
+    let rows = projects.iter().map(|project| {
+        vec![
+            project.name.clone(),
+            project
+                .project_type
+                .map(ProjectType::short_name)
+                .unwrap_or_default()
+                .to_owned(),
+            project.root.display().to_string(),
+            project.tags.join(", "),
+        ]
+    });
+
+    return format_table(&[header].into_iter().chain(rows).collect::<Vec<_>>());
+}
+
+#[cfg(test)]
+mod projects_command_tests {
+    use super::{format_projects, ProjectsCommand};
+
+    use color_eyre::eyre::Result;
+    use pretty_assertions::assert_eq;
+    use test_case::test_case;
+
+    use crate::workspace::{load_projects, test_bed::MockNxWorkspace, ProjectType};
+
+    #[test_case("none", None, &[], &["api", "shared-ui"]; "No Filters")]
+    #[test_case("type", Some(ProjectType::Library), &[], &["shared-ui"]; "Project Type")]
+    #[test_case("tag", None, &["scope:api"], &["api"]; "Tag")]
+    #[test_case("both", Some(ProjectType::Application), &["type:ui"], &[]; "Type And Tag")]
+    fn test_filter(
+        name: &str,
+        project_type: Option<ProjectType>,
+        tags: &[&str],
+        expected: &[&str],
+    ) -> Result<()> {
+        let workspace = MockNxWorkspace::new(&format!("projects_command_filter_{name}"))?;
+        let projects = load_projects(&workspace.root)?;
+        let command = ProjectsCommand {
+            json: false,
+            project_type,
+            tags: tags.iter().map(|tag| tag.to_string()).collect(),
+        };
+
+        let names: Vec<&str> = command
+            .filter(&projects)
+            .iter()
+            .map(|project| project.name.as_str())
+            .collect();
+
+        assert_eq!(names, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_format_projects() -> Result<()> {
+        let workspace = MockNxWorkspace::new("projects_command_format")?;
+        let projects = load_projects(&workspace.root)?;
+
+        assert_eq!(
+            format_projects(&projects.iter().collect::<Vec<_>>()),
+            [
+                "NAME       TYPE  ROOT            TAGS\n",
+                "api        app   apps/api        scope:api\n",
+                "shared-ui  lib   libs/shared-ui  scope:shared, type:ui\n",
+            ]
+            .concat()
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,251 @@
+use std::{
+    future::Future,
+    io::{self, Write},
+    process,
+    time::Instant,
+};
+
+use chrono::Local;
+use clap::Args;
+use clap_complete::ArgValueCompleter;
+use color_eyre::eyre::{bail, Result};
+use tokio::{process::Command, sync::mpsc::unbounded_channel};
+
+use super::{complete_configurations, complete_tasks, write_output, Cli, LoadedWorkspace, Task};
+use crate::{
+    shared::{
+        events::wait_for_exit_signal,
+        process::{ExitStatus, ProcessEvent, ProcessHandler, ProcessOutput},
+        session::{RunHistory, RunOrigin, RunRecord},
+    },
+    workspace::{nx_run_command, nx_task_name},
+};
+
+#[derive(Args, Clone, Debug)]
+pub struct RunCommand {
+    /// The target to run, written as `project:target` or
+    /// `project:target:configuration`.
+    #[arg(value_name = "PROJECT:TARGET", add = ArgValueCompleter::new(complete_tasks))]
+    pub task: String,
+
+    /// The configuration to run the target in, e.g. `production`.
+    #[arg(short, long, value_name = "NAME", add = ArgValueCompleter::new(complete_configurations))]
+    pub configuration: Option<String>,
+
+    /// Extra arguments passed on to Nx.
+    #[arg(last = true, value_name = "ARGS")]
+    pub args: Vec<String>,
+}
+
+impl RunCommand {
+    /// Runs the target like the TUI does, then exits with its status code.
+    pub async fn run(&self, cli: &Cli) -> Result<()> {
+        let (project, target, configuration) = self.parse_task()?;
+        let workspace = LoadedWorkspace::load(cli)?;
+
+        let args = [workspace.config.run_args.as_slice(), &self.args].concat();
+        let command = nx_run_command(&project, &target, configuration.as_deref(), &args);
+        let task_name = nx_task_name(&project, &target, configuration.as_deref());
+
+        let started_at = Local::now().timestamp();
+        let started = Instant::now();
+        let status = stream_output(
+            command,
+            &task_name,
+            &mut io::stdout(),
+            wait_for_exit_signal(),
+        )
+        .await?;
+
+        let record = RunRecord {
+            project,
+            target,
+            configuration,
+            args: self.args.clone(),
+            started_at,
+            duration_ms: started.elapsed().as_millis() as u64,
+            exit_code: status.code(),
+            origin: RunOrigin::Cli,
+        };
+        let run_history = RunHistory::new(&workspace.config.data_dir, &workspace.root);
+        if let Err(error) = run_history.append(&record) {
+            eprintln!("Warning: Unable to record the run in the history: {error}");
+        }
+
+        if status != ExitStatus::Code(0) {
+            process::exit(status.shell_status());
+        }
+
+        Ok(())
+    }
+
+    /// The project, target and configuration to run, which may be given
+    /// either in the task or with `--configuration`, but not both.
+    fn parse_task(&self) -> Result<(String, String, Option<String>)> {
+        let Task {
+            project,
+            target: Some(target),
+            configuration,
+        } = Task::parse(&self.task)?
+        else {
+            bail!(
+                "Expected a target written as `project:target`, not `{}`.",
+                self.task
+            );
+        };
+
+        let configuration = match (configuration, &self.configuration) {
+            (Some(_), Some(_)) => bail!("The configuration is given twice."),
+            (Some(configuration), None) => Some(configuration),
+            (None, configuration) => configuration.clone(),
+        };
+
+        return Ok((project, target, configuration));
+    }
+}
+
+/// Runs the command, writing each line of its output prefixed with the task
+/// name. Returns how it exited, or the exit signal lazynx received while it
+/// ran.
+async fn stream_output(
+    command: Command,
+    task_name: &str,
+    out: &mut impl Write,
+    exit_signal: impl Future<Output = Option<i32>>,
+) -> Result<ExitStatus> {
+    let (tx, mut rx) = unbounded_channel();
+    let process_handler = ProcessHandler::new(tx);
+    process_handler.spawn(command)?;
+
+    // The target runs in its own process group, out of reach of the signals
+    // sent to lazynx, so they are passed on.
+    tokio::pin!(exit_signal);
+    let mut received_signal = None;
+
+    loop {
+        tokio::select! {
+            output = rx.recv() => match output {
+                Some(ProcessOutput { event, .. }) => match event {
+                    ProcessEvent::Stdout(line) | ProcessEvent::Stderr(line) => {
+                        write_output(out, &format!("{task_name} │ {line}\n"))?
+                    }
+                    ProcessEvent::Exited(status) => {
+                        return Ok(received_signal.map_or(status, ExitStatus::Signal));
+                    }
+                },
+                None => break,
+            },
+            Some(signal) = &mut exit_signal, if received_signal.is_none() => {
+                received_signal = Some(signal);
+                process_handler.terminate().await;
+            }
+        }
+    }
+
+    bail!("{task_name} stopped without an exit status.");
+}
+
+#[cfg(test)]
+mod run_command_tests {
+    use super::{stream_output, RunCommand};
+
+    use std::future::pending;
+
+    use color_eyre::eyre::Result;
+    use pretty_assertions::assert_eq;
+    use test_case::test_case;
+    use tokio::{
+        process::Command,
+        time::{sleep, timeout, Duration},
+    };
+
+    use crate::shared::process::ExitStatus;
+
+    fn run_command(task: &str, configuration: Option<&str>) -> RunCommand {
+        return RunCommand {
+            task: task.to_owned(),
+            configuration: configuration.map(str::to_owned),
+            args: Vec::new(),
+        };
+    }
+
+    #[test_case("api:build", None, None; "Target")]
+    #[test_case("api:build:production", None, Some("production"); "Configuration In Target")]
+    #[test_case("api:build", Some("production"), Some("production"); "Configuration Flag")]
+    fn test_parse_task(
+        task: &str,
+        flag: Option<&str>,
+        expected_configuration: Option<&str>,
+    ) -> Result<()> {
+        let task = run_command(task, flag).parse_task()?;
+
+        assert_eq!(
+            task,
+            (
+                String::from("api"),
+                String::from("build"),
+                expected_configuration.map(str::to_owned)
+            )
+        );
+
+        Ok(())
+    }
+
+    #[test_case("api", None; "Missing Target")]
+    #[test_case(":build", None; "Missing Project")]
+    #[test_case("api:build:production", Some("development"); "Configuration Given Twice")]
+    fn test_parse_invalid_task(task: &str, flag: Option<&str>) {
+        assert!(run_command(task, flag).parse_task().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_stream_output_prefixes_lines() -> Result<()> {
+        let mut command = Command::new("sh");
+        command.args(["-c", "echo compiled; exit 3"]);
+        let mut out = Vec::new();
+
+        let status = stream_output(command, "api:build", &mut out, pending()).await?;
+
+        assert_eq!(status, ExitStatus::Code(3));
+        assert_eq!(String::from_utf8(out)?, "api:build │ compiled\n");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_stream_output_reports_the_signal() -> Result<()> {
+        let mut command = Command::new("sh");
+        command.args(["-c", "kill -TERM $$"]);
+
+        let status = stream_output(command, "api:serve", &mut Vec::new(), pending()).await?;
+
+        assert_eq!(status, ExitStatus::Signal(libc::SIGTERM));
+        assert_eq!(status.shell_status(), 143);
+
+        Ok(())
+    }
+
+    #[test_case(libc::SIGINT, 130; "Interrupt")]
+    #[test_case(libc::SIGTERM, 143; "Terminate")]
+    #[test_case(libc::SIGHUP, 129; "Hangup")]
+    #[tokio::test]
+    async fn test_exit_signal_stops_the_target(signal: i32, expected_status: i32) -> Result<()> {
+        let mut command = Command::new("sh");
+        command.args(["-c", "sleep 30"]);
+        let exit_signal = async move {
+            sleep(Duration::from_millis(100)).await;
+            Some(signal)
+        };
+
+        let status = timeout(
+            Duration::from_secs(5),
+            stream_output(command, "api:serve", &mut Vec::new(), exit_signal),
+        )
+        .await??;
+
+        assert_eq!(status, ExitStatus::Signal(signal));
+        assert_eq!(status.shell_status(), expected_status);
+
+        Ok(())
+    }
+}
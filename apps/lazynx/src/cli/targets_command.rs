@@ -0,0 +1,79 @@
+use clap::Args;
+use clap_complete::ArgValueCompleter;
+use color_eyre::eyre::Result;
+
+use super::{complete_projects, format_table, print_output, Cli, LoadedWorkspace};
+use crate::workspace::Project;
+
+#[derive(Args, Clone, Debug)]
+pub struct TargetsCommand {
+    /// The project to list the targets of.
+    #[arg(add = ArgValueCompleter::new(complete_projects))]
+    pub project: String,
+
+    /// Print the targets as JSON.
+    #[arg(long)]
+    pub json: bool,
+}
+
+impl TargetsCommand {
+    pub fn run(&self, cli: &Cli) -> Result<()> {
+        let workspace = LoadedWorkspace::load(cli)?;
+        let project = workspace.find_project(&self.project)?;
+
+        let output = match self.json {
+            true => serde_json::to_string_pretty(&project.targets)? + "\n",
+            false => format_targets(project),
+        };
+
+        return print_output(&output);
+    }
+}
+
+fn format_targets(project: &Project) -> String {
+    let header = ["TARGET", "EXECUTOR", "CONFIGURATIONS"]
+        .map(String::from)
+        .to_vec();
+    let rows = project.targets.iter().map(|(name, target)| {
+        vec![
+            name.clone(),
+            target.executor.clone().unwrap_or_default(),
+            target
+                .configurations
+                .keys()
+                .cloned()
+                .collect::<Vec<_>>()
+                .join(", "),
+        ]
+    });
+
+    return format_table(&[header].into_iter().chain(rows).collect::<Vec<_>>());
+}
+
+#[cfg(test)]
+mod targets_command_tests {
+    use super::format_targets;
+
+    use color_eyre::eyre::Result;
+    use pretty_assertions::assert_eq;
+
+    use crate::workspace::{load_projects, test_bed::MockNxWorkspace};
+
+    #[test]
+    fn test_format_targets() -> Result<()> {
+        let workspace = MockNxWorkspace::new("targets_command_format")?;
+        let projects = load_projects(&workspace.root)?;
+
+        assert_eq!(
+            format_targets(&projects[0]),
+            [
+                "TARGET  EXECUTOR     CONFIGURATIONS\n",
+                "build   @nx/js:tsc   production\n",
+                "serve   @nx/js:node\n",
+            ]
+            .concat()
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,62 @@
+use color_eyre::eyre::{bail, Result};
+
+/// A project, optionally narrowed down to one of its targets and a
+/// configuration, written like Nx does: `project[:target[:configuration]]`.
+#[derive(Debug, PartialEq)]
+pub struct Task {
+    pub project: String,
+    pub target: Option<String>,
+    pub configuration: Option<String>,
+}
+
+impl Task {
+    pub fn parse(text: &str) -> Result<Self> {
+        let mut parts = text.splitn(3, ':');
+        let project = parts.next().unwrap_or_default();
+        let target = parts.next();
+        let configuration = parts.next();
+
+        if project.is_empty() || target == Some("") || configuration == Some("") {
+            bail!("Expected `project`, `project:target` or `project:target:configuration`, not `{text}`.");
+        }
+
+        return Ok(Task {
+            project: project.to_owned(),
+            target: target.map(str::to_owned),
+            configuration: configuration.map(str::to_owned),
+        });
+    }
+}
+
+#[cfg(test)]
+mod task_tests {
+    use super::Task;
+
+    use color_eyre::eyre::Result;
+    use pretty_assertions::assert_eq;
+    use test_case::test_case;
+
+    #[test_case("api", None, None; "Project")]
+    #[test_case("api:build", Some("build"), None; "Target")]
+    #[test_case("api:build:production", Some("build"), Some("production"); "Configuration")]
+    fn test_parse(text: &str, target: Option<&str>, configuration: Option<&str>) -> Result<()> {
+        assert_eq!(
+            Task::parse(text)?,
+            Task {
+                project: String::from("api"),
+                target: target.map(str::to_owned),
+                configuration: configuration.map(str::to_owned),
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test_case(""; "Empty")]
+    #[test_case(":build"; "Missing Project")]
+    #[test_case("api:"; "Empty Target")]
+    #[test_case("api:build:"; "Empty Configuration")]
+    fn test_parse_invalid(text: &str) {
+        assert!(Task::parse(text).is_err());
+    }
+}
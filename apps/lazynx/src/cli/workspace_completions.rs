@@ -0,0 +1,225 @@
+use std::{
+    env,
+    ffi::{OsStr, OsString},
+};
+
+use clap::{CommandFactory, FromArgMatches};
+use clap_complete::CompletionCandidate;
+
+use super::{Cli, Command, LoadedWorkspace, Task};
+use crate::workspace::Project;
+
+/// Completes the name of a project.
+pub fn complete_projects(current: &OsStr) -> Vec<CompletionCandidate> {
+    let cli = completing_cli(completed_words());
+
+    return candidates(project_names(
+        &cached_projects(cli),
+        &current.to_string_lossy(),
+    ));
+}
+
+/// Completes `project:target`, and `project:target:configuration` once the
+/// target is written out.
+pub fn complete_tasks(current: &OsStr) -> Vec<CompletionCandidate> {
+    let cli = completing_cli(completed_words());
+
+    return candidates(task_names(
+        &cached_projects(cli),
+        &current.to_string_lossy(),
+    ));
+}
+
+/// Completes the name of a configuration of the target being run, or of any
+/// target while none is written yet.
+pub fn complete_configurations(current: &OsStr) -> Vec<CompletionCandidate> {
+    let cli = completing_cli(completed_words());
+    let task = match &cli.command {
+        Some(Command::Run(run)) => Task::parse(&run.task).ok(),
+        _ => None,
+    };
+
+    return candidates(configuration_names(
+        &cached_projects(cli),
+        task.as_ref(),
+        &current.to_string_lossy(),
+    ));
+}
+
+/// The words of the command line being completed, which the shell passes
+/// after a `--`.
+fn completed_words() -> impl Iterator<Item = OsString> {
+    return env::args_os().skip_while(|arg| arg != "--").skip(1);
+}
+
+/// The projects of the workspace the command line being completed points
+/// to. Completions run on every keypress, so these come from the cache
+/// whenever there is one.
+fn cached_projects(mut cli: Cli) -> Vec<Project> {
+    if cli.enter_workspace().is_err() {
+        return Vec::new();
+    }
+
+    return LoadedWorkspace::load_cached(&cli)
+        .map(|workspace| workspace.projects)
+        .unwrap_or_default();
+}
+
+/// Parses the command line being completed for its flags, like the workspace
+/// or config file it names. Since its last word is still being written, it
+/// may not parse cleanly; whatever does is kept.
+fn completing_cli(words: impl IntoIterator<Item = OsString>) -> Cli {
+    return Cli::command()
+        .ignore_errors(true)
+        .try_get_matches_from(words)
+        .ok()
+        .and_then(|matches| Cli::from_arg_matches(&matches).ok())
+        .unwrap_or_default();
+}
+
+fn candidates(values: Vec<String>) -> Vec<CompletionCandidate> {
+    return values.into_iter().map(CompletionCandidate::new).collect();
+}
+
+fn project_names(projects: &[Project], current: &str) -> Vec<String> {
+    return projects
+        .iter()
+        .map(|project| project.name.clone())
+        .filter(|name| name.starts_with(current))
+        .collect();
+}
+
+fn task_names(projects: &[Project], current: &str) -> Vec<String> {
+    let mut names = Vec::new();
+
+    for project in projects {
+        for (target_name, target) in &project.targets {
+            let task = format!("{}:{target_name}", project.name);
+            if task.starts_with(current) {
+                names.push(task.clone());
+            }
+
+            if current.starts_with(&format!("{task}:")) {
+                names.extend(
+                    target
+                        .configurations
+                        .keys()
+                        .map(|configuration| format!("{task}:{configuration}"))
+                        .filter(|name| name.starts_with(current)),
+                );
+            }
+        }
+    }
+
+    return names;
+}
+
+fn configuration_names(projects: &[Project], task: Option<&Task>, current: &str) -> Vec<String> {
+    let is_part_of_task = |project: &str, target: &str| match task {
+        Some(task) => task.project == project && task.target.as_deref().is_none_or(|t| t == target),
+        None => true,
+    };
+
+    let mut names: Vec<String> = projects
+        .iter()
+        .flat_map(|project| {
+            project
+                .targets
+                .iter()
+                .filter(|(name, _)| is_part_of_task(&project.name, name))
+                .map(|(_, target)| target)
+        })
+        .flat_map(|target| target.configurations.keys())
+        .filter(|name| name.starts_with(current))
+        .cloned()
+        .collect();
+    names.sort();
+    names.dedup();
+
+    return names;
+}
+
+#[cfg(test)]
+mod workspace_completions_tests {
+    use super::{completing_cli, configuration_names, project_names, task_names, Task};
+
+    use std::{ffi::OsString, path::PathBuf};
+
+    use color_eyre::eyre::Result;
+    use pretty_assertions::assert_eq;
+    use test_case::test_case;
+
+    use crate::workspace::{load_projects, test_bed::MockNxWorkspace};
+
+    #[test_case("", &["api", "shared-ui"]; "Everything")]
+    #[test_case("sh", &["shared-ui"]; "Prefix")]
+    #[test_case("web", &[]; "No Match")]
+    fn test_project_names(current: &str, expected: &[&str]) -> Result<()> {
+        let workspace = MockNxWorkspace::new(&format!("completions_projects_{current}"))?;
+        let projects = load_projects(&workspace.root)?;
+
+        assert_eq!(project_names(&projects, current), expected);
+
+        Ok(())
+    }
+
+    #[test_case("", &["api:build", "api:serve", "shared-ui:lint", "shared-ui:test"]; "Everything")]
+    #[test_case("api:", &["api:build", "api:serve"]; "Targets Of Project")]
+    #[test_case("api:build", &["api:build"]; "Whole Task")]
+    #[test_case("api:build:", &["api:build:production"]; "Configurations")]
+    #[test_case("api:serve:", &[]; "No Configurations")]
+    fn test_task_names(current: &str, expected: &[&str]) -> Result<()> {
+        let workspace = MockNxWorkspace::new(&format!("completions_tasks_{current}"))?;
+        let projects = load_projects(&workspace.root)?;
+
+        assert_eq!(task_names(&projects, current), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_configuration_names_are_listed_once() -> Result<()> {
+        let workspace = MockNxWorkspace::new("completions_configurations")?;
+        workspace.write(
+            "apps/web/project.json",
+            r#"{ "name": "web", "targets": { "build": { "configurations": { "production": {}, "development": {} } } } }"#,
+        )?;
+        let projects = load_projects(&workspace.root)?;
+
+        assert_eq!(
+            configuration_names(&projects, None, ""),
+            ["development", "production"]
+        );
+        assert_eq!(configuration_names(&projects, None, "p"), ["production"]);
+
+        Ok(())
+    }
+
+    #[test_case("web:build", &["development", "production"]; "Target")]
+    #[test_case("web:serve", &["staging"]; "Other Target")]
+    #[test_case("api:serve", &[]; "Target Without Configurations")]
+    #[test_case("web", &["development", "production", "staging"]; "Project Only")]
+    fn test_configuration_names_of_task(task: &str, expected: &[&str]) -> Result<()> {
+        let workspace = MockNxWorkspace::new(&format!("completions_task_configurations_{task}"))?;
+        workspace.write(
+            "apps/web/project.json",
+            r#"{ "name": "web", "targets": { "build": { "configurations": { "production": {}, "development": {} } }, "serve": { "configurations": { "staging": {} } } } }"#,
+        )?;
+        let projects = load_projects(&workspace.root)?;
+        let task = Task::parse(task)?;
+
+        assert_eq!(configuration_names(&projects, Some(&task), ""), expected);
+
+        Ok(())
+    }
+
+    #[test_case(&["lazynx", "-w", "/repo", "--data-dir", "/data", "run", "api:b"]; "Complete Word")]
+    #[test_case(&["lazynx", "--workspace=/repo", "--data-dir", "/data", "--project", ""]; "Empty Word")]
+    #[test_case(&["lazynx", "-w", "/repo", "--data-dir", "/data", "--pro"]; "Partial Flag")]
+    fn test_completing_cli(words: &[&str]) {
+        let cli = completing_cli(words.iter().map(OsString::from));
+
+        assert_eq!(cli.workspace, Some(PathBuf::from("/repo")));
+        assert_eq!(cli.data_dir, Some(PathBuf::from("/data")));
+    }
+}
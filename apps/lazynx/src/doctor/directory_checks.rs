@@ -0,0 +1,59 @@
+use std::{fs, path::Path};
+
+use crate::shared::{config::utils::project_dirs, overlay::Check};
+
+/// Where lazynx keeps its files unless told otherwise.
+pub fn check_platform_dirs() -> Check {
+    return match project_dirs() {
+        Ok(dirs) => Check::pass(
+            "Platform directories",
+            &dirs.data_local_dir().display().to_string(),
+        ),
+        Err(error) => Check::warn(
+            "Platform directories",
+            &format!("{error}, so the working directory is used instead"),
+        ),
+    };
+}
+
+/// Whether lazynx can create files in the directory, creating it if needed.
+pub fn check_writable(name: &str, dir: &Path) -> Check {
+    let probe = dir.join(".lazynx-doctor");
+    let result = fs::create_dir_all(dir)
+        .and_then(|()| fs::write(&probe, ""))
+        .and_then(|()| fs::remove_file(&probe));
+
+    return match result {
+        Ok(()) => Check::pass(name, &dir.display().to_string()),
+        Err(error) => Check::fail(name, &format!("{} is not writable: {error}", dir.display())),
+    };
+}
+
+#[cfg(test)]
+mod directory_checks_tests {
+    use super::check_writable;
+
+    use color_eyre::eyre::Result;
+    use pretty_assertions::assert_eq;
+    use test_utils::TempDir;
+
+    use crate::shared::overlay::CheckStatus;
+
+    #[test]
+    fn test_check_writable() -> Result<()> {
+        let root = TempDir::new("directory_checks_writable")?;
+        root.write("file", "")?;
+
+        let created = check_writable("Data directory", &root.join("data"));
+        assert_eq!(created.status, CheckStatus::Pass);
+        assert!(
+            root.join("data").is_dir(),
+            "The directory should be created."
+        );
+
+        let blocked = check_writable("Data directory", &root.join("file/data"));
+        assert_eq!(blocked.status, CheckStatus::Fail);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,14 @@
+mod directory_checks;
+use directory_checks::{check_platform_dirs, check_writable};
+
+mod doctor_report;
+pub use doctor_report::DoctorReport;
+
+mod node_checks;
+use node_checks::{check_node, check_package_manager};
+
+mod nx_checks;
+use nx_checks::{check_daemon, check_nx_installed, check_nx_json, check_projects};
+
+mod terminal_checks;
+use terminal_checks::{check_colors, check_keyboard};
@@ -0,0 +1,182 @@
+use std::path::Path;
+
+use super::{
+    check_colors, check_daemon, check_keyboard, check_node, check_nx_installed, check_nx_json,
+    check_package_manager, check_platform_dirs, check_projects, check_writable,
+};
+use crate::{
+    shared::{
+        config::Config,
+        overlay::{Check, CheckStatus},
+    },
+    workspace::find_workspace_root,
+};
+
+/// The outcome of every check `lazynx doctor` runs on the environment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DoctorReport {
+    pub checks: Vec<Check>,
+}
+
+impl DoctorReport {
+    /// Runs every check for the workspace containing `dir`. The terminal is
+    /// only asked about its keyboard support when `query_terminal` is set.
+    pub fn run(config: &Config, dir: &Path, query_terminal: bool) -> Self {
+        let mut checks = Vec::new();
+
+        match find_workspace_root(dir) {
+            Some(root) => checks.extend([
+                Check::pass("Workspace", &root.display().to_string()),
+                check_node(),
+                check_package_manager(&root),
+                check_nx_installed(&root),
+                check_nx_json(&root),
+                check_daemon(&root),
+                check_projects(&root, &config.hidden_projects),
+            ]),
+            None => checks.extend([
+                Check::fail(
+                    "Workspace",
+                    &format!("{} is not inside an Nx workspace", dir.display()),
+                ),
+                check_node(),
+            ]),
+        }
+
+        checks.extend([
+            check_colors(),
+            check_keyboard(query_terminal),
+            check_platform_dirs(),
+            check_writable("Config directory", &config.config_home),
+            check_writable("Data directory", &config.data_dir),
+        ]);
+
+        return DoctorReport { checks };
+    }
+
+    pub fn has_failures(&self) -> bool {
+        return self
+            .checks
+            .iter()
+            .any(|check| check.status == CheckStatus::Fail);
+    }
+
+    /// One line per check followed by a tally, as plain text that can be
+    /// pasted into a bug report.
+    pub fn format(&self) -> String {
+        let name_width = self
+            .checks
+            .iter()
+            .map(|check| check.name.chars().count())
+            .max()
+            .unwrap_or_default();
+
+        let mut output = String::new();
+        for check in &self.checks {
+            output += &format!(
+                "[{}] {:<name_width$}  {}\n",
+                check.status.label(),
+                check.name,
+                check.detail
+            );
+        }
+
+        let count = |status| self.checks.iter().filter(|c| c.status == status).count();
+        let warnings = match count(CheckStatus::Warn) {
+            1 => String::from("1 warning"),
+            count => format!("{count} warnings"),
+        };
+        output += &format!(
+            "\n{} passed, {warnings}, {} failed\n",
+            count(CheckStatus::Pass),
+            count(CheckStatus::Fail)
+        );
+
+        return output;
+    }
+}
+
+#[cfg(test)]
+mod doctor_report_tests {
+    use super::DoctorReport;
+
+    use color_eyre::eyre::Result;
+    use pretty_assertions::assert_eq;
+    use test_utils::TempDir;
+
+    use crate::{
+        shared::{
+            config::Config,
+            overlay::{Check, CheckStatus},
+        },
+        workspace::test_bed::MockNxWorkspace,
+    };
+
+    #[test]
+    fn test_format() {
+        let report = DoctorReport {
+            checks: vec![
+                Check::pass("Node.js", "v20.11.0"),
+                Check::warn("Nx daemon", "Turned off by NX_DAEMON=false"),
+            ],
+        };
+
+        assert_eq!(
+            report.format(),
+            "[pass] Node.js    v20.11.0\n\
+             [warn] Nx daemon  Turned off by NX_DAEMON=false\n\
+             \n\
+             1 passed, 1 warning, 0 failed\n"
+        );
+        assert!(!report.has_failures());
+    }
+
+    #[test]
+    fn test_run_in_workspace() -> Result<()> {
+        let workspace = MockNxWorkspace::new("doctor_report_run")?;
+        let config = Config {
+            config_home: workspace.root.join("tmp/config"),
+            data_dir: workspace.root.join("tmp/data"),
+            ..Config::default()
+        };
+
+        let report = DoctorReport::run(&config, &workspace.root.join("apps"), false);
+        let status_of = |name: &str| {
+            report
+                .checks
+                .iter()
+                .find(|check| check.name == name)
+                .map(|check| check.status)
+        };
+
+        assert_eq!(status_of("Workspace"), Some(CheckStatus::Pass));
+        assert_eq!(status_of("Nx"), Some(CheckStatus::Fail));
+        assert_eq!(status_of("nx.json"), Some(CheckStatus::Pass));
+        assert_eq!(status_of("Projects"), Some(CheckStatus::Pass));
+        assert_eq!(status_of("Data directory"), Some(CheckStatus::Pass));
+        assert!(report.has_failures());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_run_outside_workspace() -> Result<()> {
+        let dir = TempDir::new("doctor_report_outside")?;
+        let config = Config {
+            config_home: dir.join("config"),
+            data_dir: dir.join("data"),
+            ..Config::default()
+        };
+
+        let report = DoctorReport::run(&config, &dir.path, false);
+
+        assert_eq!(report.checks[0].name, "Workspace");
+        assert_eq!(report.checks[0].status, CheckStatus::Fail);
+        assert!(
+            report.checks.iter().all(|check| check.name != "nx.json"),
+            "Workspace checks should be skipped."
+        );
+
+        Ok(())
+    }
+}
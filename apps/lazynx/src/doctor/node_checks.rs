@@ -0,0 +1,130 @@
+use std::{fs, path::Path, process::Command};
+
+use color_eyre::eyre::{bail, Result};
+use serde_json::Value;
+
+use crate::shared::overlay::Check;
+
+/// The oldest Node.js major version current Nx releases run on.
+const MIN_NODE_MAJOR: u32 = 18;
+
+/// Lock files and the package manager that writes them, in the order they
+/// are looked for.
+const LOCK_FILES: [(&str, &str); 5] = [
+    ("bun.lock", "bun"),
+    ("bun.lockb", "bun"),
+    ("pnpm-lock.yaml", "pnpm"),
+    ("yarn.lock", "yarn"),
+    ("package-lock.json", "npm"),
+];
+
+pub fn check_node() -> Check {
+    return match program_version("node") {
+        Ok(version) => check_node_version(&version),
+        Err(error) => Check::fail("Node.js", &error.to_string()),
+    };
+}
+
+fn check_node_version(version: &str) -> Check {
+    let major = version
+        .trim_start_matches('v')
+        .split('.')
+        .next()
+        .and_then(|major| major.parse::<u32>().ok());
+
+    return match major {
+        Some(major) if major >= MIN_NODE_MAJOR => Check::pass("Node.js", version),
+        _ => Check::warn(
+            "Node.js",
+            &format!("{version} is older than Nx supports (v{MIN_NODE_MAJOR} or later)"),
+        ),
+    };
+}
+
+/// Checks the package manager the workspace uses is installed.
+pub fn check_package_manager(root: &Path) -> Check {
+    let (name, reason) = match detect_package_manager(root) {
+        Some(detected) => detected,
+        None => {
+            let version = program_version("npm").unwrap_or_else(|_| String::from("not found"));
+            return Check::warn(
+                "Package manager",
+                &format!("No lock file found, assuming npm ({version})"),
+            );
+        }
+    };
+
+    return match program_version(&name) {
+        Ok(version) => Check::pass("Package manager", &format!("{name} {version} ({reason})")),
+        Err(error) => Check::fail("Package manager", &format!("{error} ({reason})")),
+    };
+}
+
+/// The package manager named by `packageManager` in `package.json`, or else
+/// the one whose lock file is in the workspace, with where it was found.
+fn detect_package_manager(root: &Path) -> Option<(String, String)> {
+    let package_manager = fs::read_to_string(root.join("package.json"))
+        .ok()
+        .and_then(|package| serde_json::from_str::<Value>(&package).ok())
+        .and_then(|package| package["packageManager"].as_str().map(str::to_owned));
+    if let Some(package_manager) = package_manager {
+        let name = package_manager.split('@').next().unwrap_or_default();
+        return Some((name.to_owned(), String::from("from package.json")));
+    }
+
+    return LOCK_FILES
+        .iter()
+        .find(|(lock_file, _)| root.join(lock_file).is_file())
+        .map(|(lock_file, name)| (name.to_string(), format!("from {lock_file}")));
+}
+
+/// The first line `<program> --version` prints.
+fn program_version(program: &str) -> Result<String> {
+    let Ok(output) = Command::new(program).arg("--version").output() else {
+        bail!("`{program}` was not found on the PATH");
+    };
+    if !output.status.success() {
+        bail!("`{program} --version` failed with {}", output.status);
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    return Ok(stdout.lines().next().unwrap_or_default().trim().to_owned());
+}
+
+#[cfg(test)]
+mod node_checks_tests {
+    use super::{check_node_version, detect_package_manager};
+
+    use color_eyre::eyre::Result;
+    use pretty_assertions::assert_eq;
+    use test_case::test_case;
+
+    use crate::{shared::overlay::CheckStatus, workspace::test_bed::MockNxWorkspace};
+
+    #[test_case("v20.11.0", CheckStatus::Pass; "Supported")]
+    #[test_case("v16.20.2", CheckStatus::Warn; "Too Old")]
+    #[test_case("unknown", CheckStatus::Warn; "Unreadable")]
+    fn test_check_node_version(version: &str, expected: CheckStatus) {
+        assert_eq!(check_node_version(version).status, expected);
+    }
+
+    #[test]
+    fn test_detect_package_manager() -> Result<()> {
+        let workspace = MockNxWorkspace::new("node_checks_package_manager")?;
+        assert_eq!(detect_package_manager(&workspace.root), None);
+
+        workspace.write("yarn.lock", "")?;
+        assert_eq!(
+            detect_package_manager(&workspace.root),
+            Some((String::from("yarn"), String::from("from yarn.lock")))
+        );
+
+        workspace.write("package.json", r#"{ "packageManager": "pnpm@9.1.0" }"#)?;
+        assert_eq!(
+            detect_package_manager(&workspace.root),
+            Some((String::from("pnpm"), String::from("from package.json")))
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,198 @@
+use std::{env, fs, path::Path};
+
+use serde_json::Value;
+
+use crate::{
+    shared::overlay::Check,
+    workspace::{installed_nx_version, load_projects},
+};
+
+pub fn check_nx_installed(root: &Path) -> Check {
+    return match installed_nx_version(root) {
+        Some(version) => Check::pass("Nx", &version),
+        None => Check::fail(
+            "Nx",
+            "Not found in node_modules, install the workspace's dependencies first",
+        ),
+    };
+}
+
+pub fn check_nx_json(root: &Path) -> Check {
+    return match read_nx_json(root) {
+        Ok(_) => Check::pass("nx.json", "Parsed"),
+        Err(error) => Check::fail("nx.json", &error),
+    };
+}
+
+/// Whether the Nx daemon, which keeps the project graph warm, is running or
+/// has been turned off.
+pub fn check_daemon(root: &Path) -> Check {
+    let disabled_by_env = env::var("NX_DAEMON").is_ok_and(|value| value == "false");
+    return daemon_state(root, disabled_by_env);
+}
+
+fn daemon_state(root: &Path, disabled_by_env: bool) -> Check {
+    if disabled_by_env {
+        return Check::warn("Nx daemon", "Turned off by NX_DAEMON=false");
+    }
+
+    let nx_json = read_nx_json(root).unwrap_or_default();
+    let use_daemon = nx_json["useDaemonProcess"]
+        .as_bool()
+        .or(nx_json["tasksRunnerOptions"]["default"]["options"]["useDaemonProcess"].as_bool());
+    if use_daemon == Some(false) {
+        return Check::warn("Nx daemon", "Turned off by useDaemonProcess in nx.json");
+    }
+
+    let workspace_data = env::var("NX_WORKSPACE_DATA_DIRECTORY")
+        .map(|dir| root.join(dir))
+        .unwrap_or_else(|_| root.join(".nx").join("workspace-data"));
+    let process_id = fs::read_to_string(workspace_data.join("d").join("server-process.json"))
+        .ok()
+        .and_then(|contents| serde_json::from_str::<Value>(&contents).ok())
+        .and_then(|server| server["processId"].as_i64());
+
+    return match process_id {
+        Some(pid) if is_running(pid) => Check::pass("Nx daemon", &format!("Running (pid {pid})")),
+        _ => Check::pass("Nx daemon", "Not running, Nx starts it when needed"),
+    };
+}
+
+/// Lists how many projects the workspace loader finds, like the TUI would.
+pub fn check_projects(root: &Path, hidden_projects: &[String]) -> Check {
+    let projects = match load_projects(root) {
+        Ok(projects) => projects,
+        Err(error) => return Check::fail("Projects", &error.to_string()),
+    };
+
+    let hidden = projects
+        .iter()
+        .filter(|project| hidden_projects.contains(&project.name))
+        .count();
+
+    return match projects.len() {
+        0 => Check::warn(
+            "Projects",
+            "No project.json found, projects inferred by Nx plugins are not listed",
+        ),
+        count => Check::pass("Projects", &format!("{count} found, {hidden} hidden")),
+    };
+}
+
+fn read_nx_json(root: &Path) -> Result<Value, String> {
+    let contents = fs::read_to_string(root.join("nx.json")).map_err(|error| error.to_string())?;
+
+    return json5::from_str(&contents).map_err(|error| error.to_string());
+}
+
+fn is_running(pid: i64) -> bool {
+    let Ok(pid) = libc::pid_t::try_from(pid) else {
+        return false;
+    };
+    if pid <= 0 {
+        return false;
+    }
+
+    // SAFETY: `kill` has no memory safety requirements. Signal 0 only checks
+    // whether the process exists, and the pid is positive, so it never
+    // addresses a process group (0 or negative) or every process (-1).
+    return unsafe { libc::kill(pid, 0) } == 0;
+}
+
+#[cfg(test)]
+mod nx_checks_tests {
+    use super::{check_nx_installed, check_nx_json, check_projects, daemon_state, is_running};
+
+    use std::process;
+
+    use color_eyre::eyre::Result;
+    use pretty_assertions::assert_eq;
+    use test_case::test_case;
+
+    use crate::{
+        shared::overlay::{Check, CheckStatus},
+        workspace::test_bed::MockNxWorkspace,
+    };
+
+    #[test]
+    fn test_check_nx_installed() -> Result<()> {
+        let workspace = MockNxWorkspace::new("nx_checks_installed")?;
+        assert_eq!(
+            check_nx_installed(&workspace.root).status,
+            CheckStatus::Fail
+        );
+
+        workspace.write("node_modules/nx/package.json", r#"{ "version": "20.6.2" }"#)?;
+        assert_eq!(
+            check_nx_installed(&workspace.root),
+            Check::pass("Nx", "20.6.2")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_check_nx_json() -> Result<()> {
+        let workspace = MockNxWorkspace::new("nx_checks_nx_json")?;
+        workspace.write(
+            "nx.json",
+            "{\n  // Comments are fine.\n  \"npmScope\": \"acme\"\n}",
+        )?;
+        assert_eq!(check_nx_json(&workspace.root).status, CheckStatus::Pass);
+
+        workspace.write("nx.json", "{ \"npmScope\": ")?;
+        assert_eq!(check_nx_json(&workspace.root).status, CheckStatus::Fail);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_daemon_state() -> Result<()> {
+        let workspace = MockNxWorkspace::new("nx_checks_daemon")?;
+        assert_eq!(
+            daemon_state(&workspace.root, false),
+            Check::pass("Nx daemon", "Not running, Nx starts it when needed")
+        );
+        assert_eq!(
+            daemon_state(&workspace.root, true).status,
+            CheckStatus::Warn
+        );
+
+        let pid = process::id();
+        workspace.write(
+            ".nx/workspace-data/d/server-process.json",
+            &format!(r#"{{ "processId": {pid} }}"#),
+        )?;
+        assert_eq!(
+            daemon_state(&workspace.root, false),
+            Check::pass("Nx daemon", &format!("Running (pid {pid})"))
+        );
+
+        workspace.write("nx.json", r#"{ "useDaemonProcess": false }"#)?;
+        assert_eq!(
+            daemon_state(&workspace.root, false).status,
+            CheckStatus::Warn
+        );
+
+        Ok(())
+    }
+
+    #[test_case(0; "Own Process Group")]
+    #[test_case(-1; "Every Process")]
+    #[test_case(-(process::id() as i64); "Negative Pid")]
+    fn test_is_running_ignores_non_positive_pids(pid: i64) {
+        assert!(!is_running(pid), "{pid} is not the pid of a process.");
+    }
+
+    #[test]
+    fn test_check_projects() -> Result<()> {
+        let workspace = MockNxWorkspace::new("nx_checks_projects")?;
+
+        assert_eq!(
+            check_projects(&workspace.root, &[String::from("api")]),
+            Check::pass("Projects", "2 found, 1 hidden")
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,71 @@
+use std::env;
+
+use crossterm::terminal::supports_keyboard_enhancement;
+
+use crate::shared::overlay::Check;
+
+pub fn check_colors() -> Check {
+    return color_support(
+        env::var("COLORTERM").ok().as_deref(),
+        env::var("TERM").ok().as_deref(),
+    );
+}
+
+fn color_support(colorterm: Option<&str>, term: Option<&str>) -> Check {
+    if let Some(colorterm @ ("truecolor" | "24bit")) = colorterm {
+        return Check::pass("Colors", &format!("24-bit (COLORTERM={colorterm})"));
+    }
+
+    return match term {
+        None | Some("" | "dumb") => {
+            Check::warn("Colors", "TERM is not set, so colors and keys may not work")
+        }
+        Some(term) if term.contains("256color") => {
+            Check::pass("Colors", &format!("256 colors (TERM={term})"))
+        }
+        Some(term) => Check::warn(
+            "Colors",
+            &format!("16 colors (TERM={term}), hex colors in the theme are approximated"),
+        ),
+    };
+}
+
+/// Asks the terminal whether it reports keys unambiguously (the kitty
+/// keyboard protocol). Only done when `query` is set, as it needs a terminal
+/// to answer that nothing else is reading input from.
+pub fn check_keyboard(query: bool) -> Check {
+    if !query {
+        return Check::warn(
+            "Keyboard",
+            "Not checked, run `lazynx doctor` in a terminal to ask it",
+        );
+    }
+
+    return match supports_keyboard_enhancement() {
+        Ok(true) => Check::pass("Keyboard", "Supports the kitty keyboard protocol"),
+        Ok(false) => Check::warn(
+            "Keyboard",
+            "Legacy key codes only, so some combinations like Ctrl+Enter can't be told apart",
+        ),
+        Err(error) => Check::warn("Keyboard", &format!("Unable to ask the terminal: {error}")),
+    };
+}
+
+#[cfg(test)]
+mod terminal_checks_tests {
+    use super::color_support;
+
+    use pretty_assertions::assert_eq;
+    use test_case::test_case;
+
+    use crate::shared::overlay::CheckStatus;
+
+    #[test_case(Some("truecolor"), Some("xterm"), CheckStatus::Pass; "True Color")]
+    #[test_case(None, Some("xterm-256color"), CheckStatus::Pass; "256 Colors")]
+    #[test_case(None, Some("xterm"), CheckStatus::Warn; "16 Colors")]
+    #[test_case(None, Some("dumb"), CheckStatus::Warn; "Dumb Terminal")]
+    #[test_case(None, None, CheckStatus::Warn; "No Term")]
+    fn test_color_support(colorterm: Option<&str>, term: Option<&str>, expected: CheckStatus) {
+        assert_eq!(color_support(colorterm, term).status, expected);
+    }
+}
@@ -1,6 +1,14 @@
 mod app;
-mod app_status;
-mod consts;
+mod runner;
+
+#[path = "./cli/cli.mod.rs"]
+mod cli;
+
+#[path = "./doctor/doctor.mod.rs"]
+mod doctor;
+
+#[path = "./projects/projects.mod.rs"]
+mod projects;
 
 #[path = "./shared/shared.mod.rs"]
 mod shared;
@@ -11,38 +19,126 @@ mod shell;
 #[path = "./workspace/workspace.mod.rs"]
 mod workspace;
 
-use color_eyre::Result;
+use std::{env, process};
+
+use color_eyre::{eyre::eyre, Result};
 
 use app::App;
+use clap::{CommandFactory, Parser};
+use clap_complete::CompleteEnv;
+use cli::{Cli, ConfigFiles, COMPLETE_VAR};
 use crossterm::event::EventStream;
-use shared::{config::Config, errors, events::EventLoopHandler, logger, tui::TuiRunner};
+use runner::Runner;
+use shared::{
+    actions::{Action, ActionBus},
+    config::{load_config, Config, ConfigWatcher, LoadedConfig},
+    errors,
+    events::EventLoopHandler,
+    logger,
+    overlay::{ErrorBox, Overlay},
+    session::SessionStore,
+    tui::TuiRunner,
+};
 use shell::AppWidget;
+use workspace::find_workspace_root;
+
+/// Loads the config, falling back to the defaults when it is invalid. Any
+/// problems are returned as popups to show once the TUI is up, rather than
+/// crashing before it starts.
+fn load_config_or_default(config_files: &Result<ConfigFiles>, cli: Cli) -> (Config, Vec<ErrorBox>) {
+    let loaded = match config_files {
+        Ok(files) => load_config(&files.user, files.workspace.as_deref(), cli),
+        Err(error) => Err(eyre!("{error}")),
+    };
+
+    return match loaded {
+        Ok(LoadedConfig { config, warnings }) => {
+            let warnings = warnings
+                .iter()
+                .map(|warning| ErrorBox::new("Config Warning", warning))
+                .collect();
+            (config, warnings)
+        }
+        Err(error) => {
+            let message = format!("{error}\n\nFalling back to the default settings.");
+            (
+                Config::default(),
+                vec![ErrorBox::new("Config Error", &message)],
+            )
+        }
+    };
+}
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    let config = Config::default();
+    // Answers the shell completion scripts, exiting right away.
+    CompleteEnv::with_factory(Cli::command)
+        .var(COMPLETE_VAR)
+        .complete();
+
+    let mut cli = Cli::parse();
+
+    // Loading the config can already create error reports, which would
+    // install eyre's default hook, so ours have to be in place first. Since
+    // the config decides whether mouse and paste support get enabled, a panic
+    // restores both regardless.
+    let restore_all = TuiRunner::default()
+        .set_draw(true)
+        .set_mouse(true)
+        .set_paste(true);
+    errors::install_hooks(restore_all)?;
+
+    cli.enter_workspace()?;
+
+    if let Some(command) = &cli.command {
+        return command.run(&cli).await;
+    }
+
+    let config_files = ConfigFiles::locate(&cli);
+    let (config, config_problems) = load_config_or_default(&config_files, cli.clone());
 
     logger::init(logger::Config {
         data_dir: config.data_dir.clone(),
         log_level: config.log_level,
     })?;
 
+    let action_bus = ActionBus::default();
+    for problem in config_problems {
+        tracing::warn!("{}", problem.message);
+        action_bus.dispatch(Action::OpenOverlay(Overlay::Error(problem)))?;
+    }
+
+    if let Some(open_link) = cli.open_link()? {
+        action_bus.dispatch(open_link)?;
+    }
+
     let tui = TuiRunner::default()
         .set_draw(true)
         .set_mouse(config.enable_mouse)
         .set_paste(config.enable_paste);
 
-    errors::install_hooks(tui)?;
+    let event_loop = EventLoopHandler::new(EventStream::new(), config.tick_rate, config.frame_rate);
 
-    let frame_rate = config.frame_rate;
-    let event_loop = EventLoopHandler::new(EventStream::new(), frame_rate);
+    let session_store = find_workspace_root(&env::current_dir()?)
+        .map(|root| SessionStore::new(&config.data_dir, &root));
 
-    let mut app = App::<AppWidget>::default();
-    let backend = tui.init()?;
+    let app = App::<AppWidget>::default();
 
-    app.run(backend, config, event_loop)?;
+    let mut runner = Runner::new(app, config, action_bus, tui, event_loop)?;
+    if let Some(session_store) = session_store {
+        runner.set_session_store(session_store);
+    }
+    if let Ok(files) = config_files {
+        match ConfigWatcher::new(&files.user, files.workspace.as_deref(), cli) {
+            Ok(config_watcher) => runner.set_config_watcher(config_watcher),
+            Err(error) => tracing::warn!("Unable to watch the config files: {error}"),
+        }
+    }
+    runner.run().await?;
 
-    tui.restore()?;
+    if let Some(signal) = runner.exit_signal() {
+        process::exit(128 + signal);
+    }
 
     Ok(())
 }
@@ -0,0 +1,7 @@
+mod projects_store;
+
+mod projects_tab_widget;
+pub use projects_tab_widget::ProjectsTabWidget;
+
+mod targets_tab_widget;
+pub use targets_tab_widget::TargetsTabWidget;
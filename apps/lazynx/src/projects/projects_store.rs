@@ -0,0 +1,215 @@
+use std::path::PathBuf;
+
+use ratatui::widgets::ListState;
+
+use crate::workspace::{find_workspace_root, load_projects, Project, WorkspaceCache};
+
+#[derive(Default)]
+pub struct ProjectsStore {
+    /// Where the loaded projects are cached for the shell completions.
+    data_dir: Option<PathBuf>,
+    /// Only projects whose name contains this, ignoring case, are listed.
+    pub filter: String,
+    hidden_projects: Vec<String>,
+    pub list_state: ListState,
+    loaded_projects: Vec<Project>,
+    /// The loaded projects that are not hidden and match the filter.
+    pub projects: Vec<Project>,
+}
+
+pub enum ProjectsAction {
+    /// Caches the projects loaded from now on in the data directory.
+    CacheIn(PathBuf),
+    /// Lists only the projects whose name contains the text.
+    Filter(String),
+    /// Leaves the named projects out of the list.
+    HideProjects(Vec<String>),
+    /// Loads the projects of the Nx workspace containing the directory.
+    LoadProjects(PathBuf),
+    SelectNext,
+    SelectPrev,
+    /// Selects the project, clearing the filter if it hides the project.
+    SelectProject(String),
+}
+
+impl ProjectsStore {
+    pub fn update(&mut self, action: ProjectsAction) {
+        match action {
+            ProjectsAction::CacheIn(data_dir) => self.data_dir = Some(data_dir),
+            ProjectsAction::Filter(filter) => {
+                self.filter = filter;
+                self.show_projects();
+            }
+            ProjectsAction::HideProjects(names) => {
+                self.hidden_projects = names;
+                self.show_projects();
+            }
+            ProjectsAction::LoadProjects(dir) => self.load_projects(dir),
+            ProjectsAction::SelectNext => self.list_state.select_next(),
+            ProjectsAction::SelectPrev => self.list_state.select_previous(),
+            ProjectsAction::SelectProject(name) => {
+                let is_filtered_out = !self.projects.iter().any(|p| p.name == name)
+                    && self.loaded_projects.iter().any(|p| p.name == name);
+                if is_filtered_out {
+                    self.filter.clear();
+                    self.show_projects();
+                }
+
+                if let Some(index) = self.projects.iter().position(|p| p.name == name) {
+                    self.list_state.select(Some(index));
+                }
+            }
+        }
+    }
+
+    fn load_projects(&mut self, dir: PathBuf) {
+        let Some(root) = find_workspace_root(&dir) else {
+            tracing::warn!("No Nx workspace found at {}", dir.display());
+            return;
+        };
+
+        self.loaded_projects = load_projects(&root).unwrap_or_else(|error| {
+            tracing::error!("Unable to load projects: {error}");
+            Vec::new()
+        });
+
+        if let Some(data_dir) = &self.data_dir {
+            let cache = WorkspaceCache::new(data_dir, &root);
+            if let Err(error) = cache.save(&self.loaded_projects) {
+                tracing::warn!("Unable to cache the projects: {error}");
+            }
+        }
+
+        self.list_state.select(None);
+        self.show_projects();
+    }
+
+    /// Lists the projects that are not hidden and match the filter, keeping
+    /// the selected project selected when it is still listed.
+    fn show_projects(&mut self) {
+        let selected_name = self.get_selected_project().map(|p| p.name.clone());
+        let filter = self.filter.to_lowercase();
+
+        self.projects = self
+            .loaded_projects
+            .iter()
+            .filter(|project| !self.hidden_projects.contains(&project.name))
+            .filter(|project| project.name.to_lowercase().contains(&filter))
+            .cloned()
+            .collect();
+
+        let selected = selected_name
+            .and_then(|name| self.projects.iter().position(|p| p.name == name))
+            .or((!self.projects.is_empty()).then_some(0));
+        self.list_state.select(selected);
+    }
+
+    pub fn get_selected_project(&self) -> Option<&Project> {
+        return self
+            .list_state
+            .selected()
+            .and_then(|index| self.projects.get(index));
+    }
+}
+
+#[cfg(test)]
+mod projects_store_tests {
+    use super::{ProjectsAction, ProjectsStore};
+
+    use color_eyre::eyre::Result;
+    use pretty_assertions::assert_eq;
+
+    use crate::workspace::test_bed::MockNxWorkspace;
+
+    fn setup(name: &str) -> Result<(ProjectsStore, MockNxWorkspace)> {
+        let workspace = MockNxWorkspace::new(name)?;
+        let mut store = ProjectsStore::default();
+        store.update(ProjectsAction::LoadProjects(workspace.root.join("apps")));
+
+        return Ok((store, workspace));
+    }
+
+    fn get_selected_name(store: &ProjectsStore) -> Option<&str> {
+        return store.get_selected_project().map(|p| p.name.as_str());
+    }
+
+    #[test]
+    fn test_load_projects_selects_first() -> Result<()> {
+        let (store, _workspace) = setup("projects_store_load")?;
+
+        assert_eq!(store.projects.len(), 2);
+        assert_eq!(get_selected_name(&store), Some("api"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_select_next_and_prev() -> Result<()> {
+        let (mut store, _workspace) = setup("projects_store_select")?;
+
+        store.update(ProjectsAction::SelectNext);
+        assert_eq!(get_selected_name(&store), Some("shared-ui"));
+
+        store.update(ProjectsAction::SelectPrev);
+        assert_eq!(get_selected_name(&store), Some("api"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_hide_projects() -> Result<()> {
+        let (mut store, _workspace) = setup("projects_store_hide")?;
+        store.update(ProjectsAction::SelectNext);
+
+        store.update(ProjectsAction::HideProjects(vec![String::from("api")]));
+        assert_eq!(store.projects.len(), 1);
+        assert_eq!(get_selected_name(&store), Some("shared-ui"));
+
+        store.update(ProjectsAction::HideProjects(Vec::new()));
+        assert_eq!(store.projects.len(), 2);
+        assert_eq!(get_selected_name(&store), Some("shared-ui"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_filter_projects() -> Result<()> {
+        let (mut store, _workspace) = setup("projects_store_filter")?;
+
+        store.update(ProjectsAction::Filter(String::from("UI")));
+        assert_eq!(store.projects.len(), 1);
+        assert_eq!(get_selected_name(&store), Some("shared-ui"));
+
+        store.update(ProjectsAction::Filter(String::new()));
+        assert_eq!(store.projects.len(), 2);
+        assert_eq!(get_selected_name(&store), Some("shared-ui"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_select_project_by_name() -> Result<()> {
+        let (mut store, _workspace) = setup("projects_store_select_name")?;
+
+        store.update(ProjectsAction::SelectProject(String::from("shared-ui")));
+        assert_eq!(get_selected_name(&store), Some("shared-ui"));
+
+        store.update(ProjectsAction::SelectProject(String::from("missing")));
+        assert_eq!(get_selected_name(&store), Some("shared-ui"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_select_project_clears_filter_hiding_it() -> Result<()> {
+        let (mut store, _workspace) = setup("projects_store_select_filtered")?;
+        store.update(ProjectsAction::Filter(String::from("ui")));
+
+        store.update(ProjectsAction::SelectProject(String::from("api")));
+
+        assert_eq!(store.filter, "");
+        assert_eq!(get_selected_name(&store), Some("api"));
+
+        Ok(())
+    }
+}
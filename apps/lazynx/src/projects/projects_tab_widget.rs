@@ -0,0 +1,242 @@
+use std::{env, path::Path};
+
+use color_eyre::eyre::Result;
+use crossterm::event::Event as CrosstermEvent;
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Modifier, Style},
+    widgets::{Block, BorderType, Borders, List, ListItem},
+    Frame,
+};
+
+use super::projects_store::{ProjectsAction, ProjectsStore};
+use crate::{
+    shared::{
+        actions::Action,
+        component::Component,
+        config::Config,
+        events::Event,
+        focus::Pane,
+        keymap::{KeyContext, Keymap},
+        overlay::{Overlay, TextPrompt},
+        session::Session,
+        theme::Theme,
+    },
+    workspace::Project,
+};
+
+/// Identifies the prompt the projects filter is entered in.
+const FILTER_PROMPT_ID: &str = "projects-filter";
+
+#[derive(Default)]
+pub struct ProjectsTabWidget {
+    is_focused: bool,
+    keymap: Keymap,
+    store: ProjectsStore,
+    theme: Theme,
+}
+
+impl ProjectsTabWidget {
+    pub fn projects(&self) -> &[Project] {
+        return &self.store.projects;
+    }
+
+    pub fn selected_project(&self) -> Option<&Project> {
+        return self.store.get_selected_project();
+    }
+
+    /// Loads the projects of the Nx workspace containing the directory.
+    pub fn load_projects(&mut self, dir: &Path) {
+        self.store
+            .update(ProjectsAction::LoadProjects(dir.to_path_buf()));
+    }
+
+    fn create_filter_prompt(&self) -> Overlay {
+        let prompt =
+            TextPrompt::new(FILTER_PROMPT_ID, "Filter Projects").with_input(&self.store.filter);
+
+        return Overlay::Prompt(prompt);
+    }
+
+    fn create_tab(&self) -> Block<'static> {
+        let title = match self.store.filter.as_str() {
+            "" => String::from("─[2]─Projects─"),
+            filter => format!("─[2]─Projects─/{filter}─"),
+        };
+
+        return Block::default()
+            .title(title)
+            .title_alignment(Alignment::Left)
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(self.theme.border_style(self.is_focused));
+    }
+}
+
+impl Component for ProjectsTabWidget {
+    fn register_config_handler(&mut self, config: Config) -> Result<()> {
+        self.keymap = config.keymap;
+        self.theme = config.theme;
+        self.store
+            .update(ProjectsAction::HideProjects(config.hidden_projects));
+        self.store.update(ProjectsAction::CacheIn(config.data_dir));
+        Ok(())
+    }
+
+    fn init(&mut self) -> Result<()> {
+        self.load_projects(&env::current_dir()?);
+
+        Ok(())
+    }
+
+    fn restore_session(&mut self, session: &Session) -> Result<()> {
+        self.store
+            .update(ProjectsAction::Filter(session.project_filter.clone()));
+
+        if let Some(name) = &session.selected_project {
+            self.store
+                .update(ProjectsAction::SelectProject(name.clone()));
+        }
+
+        Ok(())
+    }
+
+    fn save_session(&self, session: &mut Session) {
+        session.project_filter = self.store.filter.clone();
+        session.selected_project = self.store.get_selected_project().map(|p| p.name.clone());
+    }
+
+    fn handle_events(&mut self, event: Event) -> Option<Action> {
+        let Event::Crossterm(CrosstermEvent::Key(key)) = event else {
+            return None;
+        };
+
+        match self.keymap.action_for(KeyContext::Projects, &key) {
+            Some(Action::SelectNext) => self.store.update(ProjectsAction::SelectNext),
+            Some(Action::SelectPrev) => self.store.update(ProjectsAction::SelectPrev),
+            Some(Action::FilterProjects) => {
+                return Some(Action::OpenOverlay(self.create_filter_prompt()))
+            }
+            action => return action,
+        }
+
+        None
+    }
+
+    fn update(&mut self, action: Action) -> Result<Option<Action>> {
+        match action {
+            Action::Focus(pane) => self.is_focused = pane == Pane::Projects,
+            Action::SelectProject(name) => self.store.update(ProjectsAction::SelectProject(name)),
+            Action::PromptSubmitted { id, value } if id == FILTER_PROMPT_ID => {
+                self.store.update(ProjectsAction::Filter(value))
+            }
+            _ => {}
+        }
+
+        Ok(None)
+    }
+
+    fn draw(&mut self, frame: &mut Frame, area: Rect) {
+        let items: Vec<ListItem> = self
+            .store
+            .projects
+            .iter()
+            .map(|project| ListItem::new(format!(" {}", project.name)))
+            .collect();
+
+        let list = List::new(items)
+            .block(self.create_tab())
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+
+        frame.render_stateful_widget(list, area, &mut self.store.list_state);
+    }
+}
+
+#[cfg(test)]
+mod projects_tab_widget_tests {
+    use super::ProjectsTabWidget;
+
+    use color_eyre::eyre::Result;
+    use crossterm::event::{Event as CrosstermEvent, KeyCode, KeyEvent, KeyModifiers};
+    use insta::assert_snapshot;
+    use pretty_assertions::assert_eq;
+    use test_utils::WidgetTestBed;
+
+    use crate::{
+        shared::{
+            actions::Action, component::Component, events::Event, focus::Pane, overlay::Overlay,
+        },
+        workspace::test_bed::MockNxWorkspace,
+    };
+
+    fn setup(name: &str) -> Result<(WidgetTestBed<ProjectsTabWidget>, MockNxWorkspace)> {
+        let workspace = MockNxWorkspace::new(name)?;
+        let mut test_bed = WidgetTestBed::<ProjectsTabWidget>::new(30, 6);
+        test_bed.widget.load_projects(&workspace.root);
+
+        return Ok((test_bed, workspace));
+    }
+
+    #[test]
+    fn test_draw_widget() -> Result<()> {
+        let (mut test_bed, _workspace) = setup("projects_tab_draw")?;
+
+        let widget = &mut test_bed.widget;
+        test_bed.terminal.draw(|f| widget.draw(f, f.area()))?;
+
+        assert_snapshot!(test_bed.terminal.backend());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_key_events_move_selection() -> Result<()> {
+        let (mut test_bed, _workspace) = setup("projects_tab_keys")?;
+        let key = KeyEvent::new(KeyCode::Char('j'), KeyModifiers::empty());
+
+        test_bed
+            .widget
+            .handle_events(Event::Crossterm(CrosstermEvent::Key(key)));
+
+        assert_eq!(test_bed.widget.store.list_state.selected(), Some(1));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_filter_prompt() -> Result<()> {
+        let (mut test_bed, _workspace) = setup("projects_tab_filter")?;
+        let key = KeyEvent::new(KeyCode::Char('/'), KeyModifiers::empty());
+
+        let Some(Action::OpenOverlay(Overlay::Prompt(prompt))) = test_bed
+            .widget
+            .handle_events(Event::Crossterm(CrosstermEvent::Key(key)))
+        else {
+            panic!("The filter prompt should have been opened.");
+        };
+        test_bed.widget.update(Action::PromptSubmitted {
+            id: prompt.id,
+            value: String::from("api"),
+        })?;
+
+        let widget = &mut test_bed.widget;
+        test_bed.terminal.draw(|f| widget.draw(f, f.area()))?;
+
+        assert_snapshot!(test_bed.terminal.backend());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_focus() -> Result<()> {
+        let mut widget = ProjectsTabWidget::default();
+
+        widget.update(Action::Focus(Pane::Projects))?;
+        assert!(widget.is_focused, "Projects pane should be focused.");
+
+        widget.update(Action::Focus(Pane::Main))?;
+        assert!(!widget.is_focused, "Projects pane should not be focused.");
+
+        Ok(())
+    }
+}
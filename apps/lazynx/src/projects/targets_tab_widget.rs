@@ -0,0 +1,264 @@
+use color_eyre::eyre::Result;
+use crossterm::event::Event as CrosstermEvent;
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Modifier, Style},
+    widgets::{Block, BorderType, Borders, List, ListItem, ListState},
+    Frame,
+};
+
+use crate::{
+    shared::{
+        actions::Action,
+        component::Component,
+        config::Config,
+        events::Event,
+        focus::Pane,
+        keymap::{KeyContext, Keymap},
+        overlay::{Overlay, SelectItem, SelectMenu},
+        theme::Theme,
+    },
+    workspace::{nx_task_name, Project},
+};
+
+/// Lists the targets of the project selected in the projects pane.
+#[derive(Default)]
+pub struct TargetsTabWidget {
+    is_focused: bool,
+    keymap: Keymap,
+    list_state: ListState,
+    project: Option<String>,
+    /// The project's targets, each with the names of its configurations.
+    targets: Vec<(String, Vec<String>)>,
+    theme: Theme,
+}
+
+impl TargetsTabWidget {
+    /// Lists the targets of the project, keeping the selected target
+    /// selected while the project stays the same.
+    pub fn show_project(&mut self, project: Option<&Project>) {
+        let name = project.map(|p| p.name.clone());
+        let selected = self
+            .list_state
+            .selected()
+            .and_then(|index| self.targets.get(index))
+            .filter(|_| name == self.project)
+            .map(|(target, _)| target.clone());
+
+        self.targets = project
+            .map(|p| {
+                p.targets
+                    .iter()
+                    .map(|(name, target)| {
+                        (
+                            name.clone(),
+                            target.configurations.keys().cloned().collect(),
+                        )
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        self.project = name;
+
+        let selected = selected
+            .and_then(|target| self.targets.iter().position(|(t, _)| *t == target))
+            .or((!self.targets.is_empty()).then_some(0));
+        self.list_state.select(selected);
+    }
+
+    /// Runs the selected target, first asking which configuration to run it
+    /// in if it has any.
+    fn run_selected_target(&self) -> Option<Action> {
+        let project = self.project.as_deref()?;
+        let (target, configurations) = self.targets.get(self.list_state.selected()?)?;
+
+        let run = |configuration: Option<&String>| Action::RunTarget {
+            project: project.to_owned(),
+            target: target.clone(),
+            configuration: configuration.cloned(),
+        };
+        if configurations.is_empty() {
+            return Some(run(None));
+        }
+
+        let items = [None]
+            .into_iter()
+            .chain(configurations.iter().map(Some))
+            .map(|configuration| {
+                let task = nx_task_name(project, target, configuration.map(String::as_str));
+                SelectItem::new(&task, run(configuration))
+            })
+            .collect();
+        let menu = SelectMenu::new(&format!("Run {project}:{target}"), items);
+
+        return Some(Action::OpenOverlay(Overlay::Select(menu)));
+    }
+
+    fn create_tab(&self) -> Block<'static> {
+        return Block::default()
+            .title("─[3]─Targets─")
+            .title_alignment(Alignment::Left)
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(self.theme.border_style(self.is_focused));
+    }
+}
+
+impl Component for TargetsTabWidget {
+    fn register_config_handler(&mut self, config: Config) -> Result<()> {
+        self.keymap = config.keymap;
+        self.theme = config.theme;
+        Ok(())
+    }
+
+    fn handle_events(&mut self, event: Event) -> Option<Action> {
+        let Event::Crossterm(CrosstermEvent::Key(key)) = event else {
+            return None;
+        };
+
+        match self.keymap.action_for(KeyContext::Targets, &key) {
+            Some(Action::SelectNext) => self.list_state.select_next(),
+            Some(Action::SelectPrev) => self.list_state.select_previous(),
+            Some(Action::RunSelectedTarget) => return self.run_selected_target(),
+            action => return action,
+        }
+
+        None
+    }
+
+    fn update(&mut self, action: Action) -> Result<Option<Action>> {
+        match action {
+            Action::Focus(pane) => self.is_focused = pane == Pane::Targets,
+            Action::RunSelectedTarget => return Ok(self.run_selected_target()),
+            _ => {}
+        }
+
+        Ok(None)
+    }
+
+    fn draw(&mut self, frame: &mut Frame, area: Rect) {
+        let items: Vec<ListItem> = self
+            .targets
+            .iter()
+            .map(|(target, _)| ListItem::new(format!(" {target}")))
+            .collect();
+
+        let list = List::new(items)
+            .block(self.create_tab())
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+
+        frame.render_stateful_widget(list, area, &mut self.list_state);
+    }
+}
+
+#[cfg(test)]
+mod targets_tab_widget_tests {
+    use super::TargetsTabWidget;
+
+    use color_eyre::eyre::Result;
+    use crossterm::event::{Event as CrosstermEvent, KeyCode, KeyEvent, KeyModifiers};
+    use insta::assert_snapshot;
+    use pretty_assertions::assert_eq;
+    use test_utils::WidgetTestBed;
+
+    use crate::{
+        projects::ProjectsTabWidget,
+        shared::{actions::Action, component::Component, events::Event, overlay::Overlay},
+        workspace::test_bed::MockNxWorkspace,
+    };
+
+    fn setup(name: &str) -> Result<(WidgetTestBed<TargetsTabWidget>, ProjectsTabWidget)> {
+        let workspace = MockNxWorkspace::new(name)?;
+        let mut projects_tab = ProjectsTabWidget::default();
+        projects_tab.load_projects(&workspace.root);
+
+        let mut test_bed = WidgetTestBed::<TargetsTabWidget>::new(30, 6);
+        test_bed
+            .widget
+            .show_project(projects_tab.selected_project());
+
+        return Ok((test_bed, projects_tab));
+    }
+
+    fn key(code: KeyCode) -> Event {
+        return Event::Crossterm(CrosstermEvent::Key(KeyEvent::new(
+            code,
+            KeyModifiers::empty(),
+        )));
+    }
+
+    #[test]
+    fn test_draw_widget() -> Result<()> {
+        let (mut test_bed, _projects_tab) = setup("targets_tab_draw")?;
+
+        let widget = &mut test_bed.widget;
+        test_bed.terminal.draw(|f| widget.draw(f, f.area()))?;
+
+        assert_snapshot!(test_bed.terminal.backend());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_enter_runs_selected_target() -> Result<()> {
+        let (mut test_bed, _projects_tab) = setup("targets_tab_run")?;
+
+        test_bed.widget.handle_events(key(KeyCode::Char('j')));
+        let action = test_bed.widget.handle_events(key(KeyCode::Enter));
+
+        assert_eq!(
+            action,
+            Some(Action::RunTarget {
+                project: String::from("api"),
+                target: String::from("serve"),
+                configuration: None,
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_enter_asks_for_the_configuration() -> Result<()> {
+        let (mut test_bed, _projects_tab) = setup("targets_tab_configuration")?;
+
+        let Some(Action::OpenOverlay(Overlay::Select(menu))) =
+            test_bed.widget.handle_events(key(KeyCode::Enter))
+        else {
+            panic!("The configurations of api:build should have been offered.");
+        };
+
+        let tasks: Vec<&str> = menu.items.iter().map(|item| item.label.as_str()).collect();
+        assert_eq!(tasks, vec!["api:build", "api:build:production"]);
+        assert_eq!(
+            menu.items[1].action,
+            Action::RunTarget {
+                project: String::from("api"),
+                target: String::from("build"),
+                configuration: Some(String::from("production")),
+            }
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_selection_is_kept_for_the_same_project() -> Result<()> {
+        let (mut test_bed, mut projects_tab) = setup("targets_tab_keep")?;
+
+        test_bed.widget.handle_events(key(KeyCode::Char('j')));
+        test_bed
+            .widget
+            .show_project(projects_tab.selected_project());
+        assert_eq!(test_bed.widget.list_state.selected(), Some(1));
+
+        projects_tab.handle_events(key(KeyCode::Char('j')));
+        test_bed
+            .widget
+            .show_project(projects_tab.selected_project());
+        assert_eq!(test_bed.widget.project.as_deref(), Some("shared-ui"));
+        assert_eq!(test_bed.widget.list_state.selected(), Some(0));
+
+        Ok(())
+    }
+}
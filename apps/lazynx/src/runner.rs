@@ -1,144 +1,321 @@
-use color_eyre::eyre::Result;
-use tokio::sync::mpsc::{UnboundedReceiver, UnboundedSender};
+use std::future;
 
-use app_config::Config;
-use common::{Action, Component, Event};
-use tui::TuiBackend;
+use color_eyre::eyre::Result;
+use ratatui::layout::Rect;
+use signal_hook::{consts::SIGSTOP, low_level::raise};
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver};
+
+use crate::shared::{
+    actions::{Action, ActionBus},
+    component::Component,
+    config::{Config, ConfigWatcher, LoadedConfig},
+    events::{Event, EventLoopHandler},
+    logger,
+    overlay::{ErrorBox, Overlay},
+    process::{ProcessHandler, ProcessOutput},
+    session::{Session, SessionStore},
+    tui::{Tui, TuiRunner},
+};
+
+/// Stops the whole process until it receives `SIGCONT` (e.g. from `fg`).
+fn stop_process() -> Result<()> {
+    raise(SIGSTOP)?;
+    Ok(())
+}
 
-pub struct Runner<TApp: Component<Config>> {
-    action_rx: UnboundedReceiver<Action>,
-    action_tx: UnboundedSender<Action>,
+pub struct Runner<TApp: Component> {
+    action_bus: ActionBus,
     app: TApp,
     config: Config,
+    config_watcher: Option<ConfigWatcher>,
+    event_loop: EventLoopHandler,
+    process_handler: ProcessHandler,
+    process_rx: UnboundedReceiver<ProcessOutput>,
+    session_store: Option<SessionStore>,
     should_quit: bool,
     should_suspend: bool,
-    tui: TuiBackend,
+    stop_process: fn() -> Result<()>,
+    tui: Tui,
+    tui_runner: TuiRunner,
 }
 
-impl<TApp: Component<Config>> Runner<TApp> {
+impl<TApp: Component> Runner<TApp> {
     pub fn new(
         app: TApp,
         config: Config,
-        action_tx: UnboundedSender<Action>,
-        action_rx: UnboundedReceiver<Action>,
-        tui: TuiBackend,
+        action_bus: ActionBus,
+        tui_runner: TuiRunner,
+        event_loop: EventLoopHandler,
     ) -> Result<Self> {
+        let (process_tx, process_rx) = unbounded_channel();
+        let tui = tui_runner.init().inspect_err(|_| {
+            let _ = tui_runner.restore();
+        })?;
+
         Ok(Self {
-            action_rx,
-            action_tx,
+            action_bus,
             app,
             config,
+            config_watcher: None,
+            event_loop,
+            process_handler: ProcessHandler::new(process_tx),
+            process_rx,
+            session_store: None,
             should_quit: false,
             should_suspend: false,
+            stop_process,
             tui,
+            tui_runner,
         })
     }
 
+    /// Applies changes to the config file while running.
+    pub fn set_config_watcher(&mut self, config_watcher: ConfigWatcher) {
+        self.config_watcher = Some(config_watcher);
+    }
+
+    /// Restores the app to where the user left off and saves its state again
+    /// on exit.
+    pub fn set_session_store(&mut self, session_store: SessionStore) {
+        self.session_store = Some(session_store);
+    }
+
+    /// Runs the app until it quits. The terminal is handed back to the shell
+    /// even if an error ends the app.
     pub async fn run(&mut self) -> Result<()> {
-        self.app.register_action_handler(self.action_tx.clone())?;
-        self.app.register_config_handler(self.config.clone())?;
-        self.app.init()?;
+        let result = self.run_app().await;
+
+        self.shutdown().await;
 
-        // loop {
-        //     if let Some(event) = self.tui.next().await {
-        //         self.handle_event(event).await?;
-        //     }
+        return result;
+    }
 
-        //     self.handle_action().await?;
+    async fn run_app(&mut self) -> Result<()> {
+        self.app.register_action_handler(self.action_bus.sender())?;
+        self.app
+            .register_process_handler(self.process_handler.clone())?;
+        self.app.register_config_handler(self.config.clone())?;
+        self.app.init()?;
 
-        //     if self.should_suspend {
-        //         self.suspend_tui()?;
-        //         continue;
-        //     }
+        if let Some(session_store) = &self.session_store {
+            self.app.restore_session(&session_store.load())?;
+        }
 
-        //     if self.should_quit {
-        //         self.tui.stop()?;
-        //         break;
-        //     }
-        // }
+        while !self.should_quit {
+            tokio::select! {
+                Some(event) = self.event_loop.recv() => self.handle_event(event).await?,
+                Some(action) = self.action_bus.recv() => self.handle_action(action).await?,
+                Some(output) = self.process_rx.recv() => {
+                    self.action_bus.dispatch(Action::ProcessOutput(output))?
+                }
+                Some(reloaded) = Self::config_changed(&mut self.config_watcher) => {
+                    self.reload_config(reloaded)?
+                }
+                else => break,
+            }
+        }
 
         Ok(())
     }
 
-    async fn handle_action(&mut self) -> Result<()> {
-        while let Ok(action) = self.action_rx.try_recv() {
-            if action != Action::Tick && action != Action::Render {
-                log::debug!("{action:?}");
-            }
+    /// Waits for the next config reload, or forever if the config file is not
+    /// watched.
+    async fn config_changed(watcher: &mut Option<ConfigWatcher>) -> Option<Result<LoadedConfig>> {
+        match watcher {
+            Some(watcher) => watcher.changed().await,
+            None => future::pending().await,
+        }
+    }
 
-            match action {
-                Action::Quit => self.should_quit = true,
-                Action::Suspend => self.should_suspend = true,
-                Action::Resume => self.should_suspend = false,
-                Action::Render => {
-                    self.tui.draw(|f| {
-                        self.app.draw(f, f.area());
-                    })?;
-                }
-                _ => {}
+    /// Applies the reloaded config to the running app. When the new config is
+    /// invalid, the current settings are kept.
+    fn reload_config(&mut self, reloaded: Result<LoadedConfig>) -> Result<()> {
+        let LoadedConfig { config, warnings } = match reloaded {
+            Ok(loaded) => loaded,
+            Err(error) => {
+                let message = format!("{error}\n\nKeeping the current settings.");
+                let error_box = ErrorBox::new("Config Error", &message);
+                return self
+                    .action_bus
+                    .dispatch(Action::OpenOverlay(Overlay::Error(error_box)));
             }
+        };
+
+        for warning in warnings {
+            let warning_box = ErrorBox::new("Config Warning", &warning);
+            self.action_bus
+                .dispatch(Action::OpenOverlay(Overlay::Error(warning_box)))?;
+        }
+
+        logger::set_log_level(config.log_level)?;
+        self.event_loop
+            .set_rates(config.tick_rate, config.frame_rate);
+        self.tui_runner
+            .update_input(config.enable_mouse, config.enable_paste)?;
+        self.app.register_config_handler(config.clone())?;
+
+        tracing::info!("Reloaded the config from {}", config.config_file.display());
+        self.config = config;
+
+        return self.render();
+    }
+
+    /// The signal that made the runner quit, if any.
+    pub fn exit_signal(&self) -> Option<i32> {
+        return self.event_loop.exit_signal();
+    }
+
+    /// Stops the event loop, terminates any child processes, saves the
+    /// session and hands the terminal back to the shell.
+    async fn shutdown(&mut self) {
+        self.event_loop.cancel();
+        self.process_handler.terminate().await;
+        self.save_session();
+
+        if let Err(error) = self.tui_runner.restore() {
+            tracing::error!("Unable to restore terminal: {error:?}");
+        }
+    }
+
+    fn save_session(&self) {
+        let Some(session_store) = &self.session_store else {
+            return;
+        };
+
+        let mut session = Session::default();
+        self.app.save_session(&mut session);
 
-            if let Some(action) = self.app.update(action.clone())? {
-                self.action_tx.send(action)?
+        if let Err(error) = session_store.save(&session) {
+            tracing::error!("Unable to save the session: {error:?}");
+        }
+    }
+
+    async fn handle_action(&mut self, action: Action) -> Result<()> {
+        match action {
+            Action::Quit => self.should_quit = true,
+            Action::Suspend => self.suspend()?,
+            Action::Resume => self.resume()?,
+            Action::ClearScreen => {
+                self.tui.clear()?;
+                self.render()?;
+            }
+            Action::Resize { x, y } => {
+                self.tui.resize(Rect::new(0, 0, x, y))?;
+                self.render()?;
             }
+            Action::Render => self.render()?,
+            Action::Error(ref message) => tracing::error!("{message}"),
+            _ => {}
+        }
+
+        if let Some(action) = self.app.update(action)? {
+            self.action_bus.dispatch(action)?;
         }
 
         Ok(())
     }
 
     async fn handle_event(&mut self, event: Event) -> Result<()> {
-        match event {
-            Event::Init => self.action_tx.send(Action::Init)?,
-            Event::Quit => self.action_tx.send(Action::Quit)?,
-            Event::Render => self.action_tx.send(Action::Render)?,
-            Event::Tick => self.action_tx.send(Action::Tick)?,
-            Event::Resize(x, y) => self.action_tx.send(Action::Resize { x, y })?,
-            other_event => {
-                if let Some(action) = self.app.handle_events(other_event.clone()) {
-                    self.action_tx.send(action)?
-                }
-            }
+        let action = match ActionBus::from_event(&event) {
+            Some(action) => Some(action),
+            None => self.app.handle_events(event),
+        };
+
+        if let Some(action) = action {
+            self.action_bus.dispatch(action)?;
+        }
+
+        Ok(())
+    }
+
+    /// Hands the terminal back to the shell and stops the process. Once it is
+    /// continued, a `Resume` action restores the TUI.
+    fn suspend(&mut self) -> Result<()> {
+        self.should_suspend = true;
+        self.tui_runner.restore()?;
+
+        (self.stop_process)()?;
+
+        self.action_bus.dispatch(Action::Resume)?;
+
+        Ok(())
+    }
+
+    fn resume(&mut self) -> Result<()> {
+        if !self.should_suspend {
+            return Ok(());
         }
 
+        self.should_suspend = false;
+        self.tui = self.tui_runner.init()?;
+        self.tui.clear()?;
+        self.render()?;
+
+        Ok(())
+    }
+
+    fn render(&mut self) -> Result<()> {
+        if self.should_suspend {
+            return Ok(());
+        }
+
+        self.tui.draw(|frame| {
+            self.app.draw(frame, frame.area());
+        })?;
+
         Ok(())
     }
 }
 
 #[cfg(test)]
-mod tests {
+mod runner_tests {
     use super::Runner;
 
-    use std::{panic, time::Duration};
+    use std::path::Path;
 
-    use color_eyre::eyre::Result;
+    use color_eyre::eyre::{eyre, Result};
+    use crossterm::event::Event as CrosstermEvent;
     use pretty_assertions::assert_eq;
-    use ratatui::layout::Rect;
+    use ratatui::{layout::Rect, Frame};
     use test_case::test_case;
+    use test_utils::TempDir;
     use tokio::{
-        sync::mpsc::{unbounded_channel, UnboundedSender},
-        time::timeout,
+        process::Command,
+        sync::mpsc::UnboundedSender,
+        time::{timeout, Duration},
     };
 
-    use app_config::Config;
-    use common::{Action, Component, Event};
+    use crate::shared::{
+        actions::{Action, ActionBus},
+        component::Component,
+        config::{Config, LoadedConfig},
+        events::{Event, EventLoopHandler},
+        focus::Pane,
+        overlay::{ErrorBox, Overlay},
+        process::{ExitStatus, ProcessEvent, ProcessOutput},
+        session::{Session, SessionStore},
+        tui::TuiRunner,
+    };
 
     #[derive(Default)]
     struct MockApp {
-        config: Config,
+        action_handler_tx: Option<UnboundedSender<Action>>,
+        config: Option<Config>,
+        follow_up: Option<Action>,
         init_called: bool,
         is_rendered: bool,
-        action_handler_tx: Option<UnboundedSender<Action>>,
+        restored_session: Option<Session>,
         updated_with: Option<Action>,
     }
 
-    impl Component<Config> for MockApp {
-        fn draw(&mut self, _f: &mut ratatui::Frame, _area: Rect) {
+    impl Component for MockApp {
+        fn draw(&mut self, _frame: &mut Frame, _area: Rect) {
             self.is_rendered = true;
         }
 
         fn handle_events(&mut self, event: Event) -> Option<Action> {
             match event {
-                Event::Closed => Some(Action::Quit),
+                Event::Crossterm(CrosstermEvent::FocusLost) => Some(Action::Quit),
                 _ => None,
             }
         }
@@ -149,58 +326,50 @@ mod tests {
         }
 
         fn register_action_handler(&mut self, tx: UnboundedSender<Action>) -> Result<()> {
-            self.action_handler_tx = Some(tx.clone());
+            self.action_handler_tx = Some(tx);
             Ok(())
         }
 
         fn register_config_handler(&mut self, config: Config) -> Result<()> {
-            self.config = config;
+            self.config = Some(config);
             Ok(())
         }
 
-        fn update(&mut self, action: Action) -> Result<Option<Action>> {
-            self.updated_with = Some(action);
-            Ok(None)
+        fn restore_session(&mut self, session: &Session) -> Result<()> {
+            self.restored_session = Some(session.clone());
+            Ok(())
         }
-    }
 
-    fn setup() -> Runner<MockApp> {
-        let mock_app = MockApp::default();
-        let mock_config = Config::default();
-
-        let tui = tui::Tui::default();
-        let (action_tx, action_rx) = unbounded_channel();
+        fn save_session(&self, session: &mut Session) {
+            session.focused_pane = Some(Pane::Main);
+        }
 
-        return Runner::new(
-            mock_app,
-            mock_config,
-            action_tx,
-            action_rx,
-            tui.init().unwrap(),
-        )
-        .unwrap();
+        fn update(&mut self, action: Action) -> Result<Option<Action>> {
+            if !matches!(action, Action::Render | Action::Tick) {
+                self.updated_with = Some(action);
+            }
+            Ok(self.follow_up.take())
+        }
     }
 
-    // @SECTION: Runner Instantiation Test
+    fn setup() -> Result<Runner<MockApp>> {
+        let mut tui = TuiRunner::default();
+        tui.set_draw(false);
 
-    #[test]
-    fn test_runner_instantiation() {
-        let (action_tx, action_rx) = unbounded_channel();
+        const TICK_RATE: f64 = 4.0;
+        const FPS: f64 = 30.0;
+        let event_loop = EventLoopHandler::new(tokio_stream::empty(), TICK_RATE, FPS);
 
-        let runner = Runner::new(
+        let mut runner = Runner::new(
             MockApp::default(),
             Config::default(),
-            action_tx,
-            action_rx,
-            tui::Tui::default().init().unwrap(),
-        );
+            ActionBus::default(),
+            tui,
+            event_loop,
+        )?;
+        runner.stop_process = || Ok(());
 
-        assert_eq!(
-            runner.is_ok(),
-            true,
-            "Runner did not instantiate: {:?}",
-            runner.err()
-        );
+        return Ok(runner);
     }
 
     // @SECTION: Runner.handle_event Tests
@@ -209,19 +378,18 @@ mod tests {
     #[test_case(Event::Quit, Action::Quit; "Quit")]
     #[test_case(Event::Render, Action::Render; "Render")]
     #[test_case(Event::Tick, Action::Tick; "Tick")]
-    #[test_case(Event::Resize(1, 2), Action::Resize { x: 1, y: 2 }; "Resize")]
-    #[test_case(Event::Closed, Action::Quit; "Any Other Event (Established in MockComponent)")]
+    #[test_case(Event::Crossterm(CrosstermEvent::Resize(1, 2)), Action::Resize { x: 1, y: 2 }; "Resize")]
+    #[test_case(Event::Crossterm(CrosstermEvent::FocusLost), Action::Quit; "Any Other Event (Established in MockApp)")]
     #[tokio::test]
     async fn test_handle_event(event: Event, expected_action: Action) -> Result<()> {
-        let mut runner = setup();
+        let mut runner = setup()?;
 
         runner.handle_event(event).await?;
 
-        let sent_action = runner.action_rx.recv().await.unwrap();
+        let sent_action = runner.action_bus.recv().await.unwrap();
         assert_eq!(
-            sent_action,
-            expected_action.clone(),
-            "{expected_action:?} was not sent. ${sent_action:?} was received instead.",
+            sent_action, expected_action,
+            "{expected_action:?} was not sent. {sent_action:?} was received instead.",
         );
 
         Ok(())
@@ -231,65 +399,146 @@ mod tests {
 
     #[tokio::test]
     async fn test_handle_action_quit() -> Result<()> {
-        let mut runner = setup();
+        let mut runner = setup()?;
         runner.should_quit = false;
 
-        runner.action_tx.send(Action::Quit)?;
-        runner.handle_action().await?;
+        runner.handle_action(Action::Quit).await?;
 
-        assert_eq!(runner.should_quit, true);
+        assert!(runner.should_quit, "Runner should have been told to quit.");
 
         Ok(())
     }
 
     #[tokio::test]
     async fn test_handle_action_suspend() -> Result<()> {
-        let mut runner = setup();
+        let mut runner = setup()?;
         runner.should_suspend = false;
 
-        runner.action_tx.send(Action::Suspend)?;
-        runner.handle_action().await?;
+        runner.handle_action(Action::Suspend).await?;
 
-        assert_eq!(runner.should_suspend, true);
+        assert!(runner.should_suspend, "Runner should have been suspended.");
+        assert_eq!(
+            runner.action_bus.recv().await,
+            Some(Action::Resume),
+            "Runner should resume once the process is continued."
+        );
 
         Ok(())
     }
 
     #[tokio::test]
     async fn test_handle_action_resume() -> Result<()> {
-        let mut runner = setup();
+        let mut runner = setup()?;
         runner.should_suspend = true;
+        runner.app.is_rendered = false;
 
-        runner.action_tx.send(Action::Resume)?;
-        runner.handle_action().await?;
+        runner.handle_action(Action::Resume).await?;
 
-        assert_eq!(runner.should_suspend, false);
+        assert!(!runner.should_suspend, "Runner should have been resumed.");
+        assert!(runner.app.is_rendered, "App should have been redrawn.");
 
         Ok(())
     }
 
     #[tokio::test]
-    async fn test_handle_action_tick() -> Result<()> {
-        let mut runner = setup();
+    async fn test_handle_action_updates_app() -> Result<()> {
+        let mut runner = setup()?;
         runner.app.updated_with = None;
 
-        runner.action_tx.send(Action::Tick)?;
-        runner.handle_action().await?;
+        runner.handle_action(Action::Help).await?;
+
+        assert_eq!(runner.app.updated_with, Some(Action::Help));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_handle_action_dispatches_follow_up() -> Result<()> {
+        let mut runner = setup()?;
+        runner.app.follow_up = Some(Action::Render);
+
+        runner.handle_action(Action::Tick).await?;
 
-        assert_eq!(runner.app.updated_with.unwrap(), Action::Tick);
+        let sent_action = runner.action_bus.recv().await;
+        assert_eq!(sent_action, Some(Action::Render));
 
         Ok(())
     }
 
     #[tokio::test]
     async fn test_handle_action_render() -> Result<()> {
-        let mut runner = setup();
+        let mut runner = setup()?;
         runner.app.is_rendered = false;
 
-        runner.action_tx.send(Action::Render)?;
-        runner.handle_action().await?;
+        runner.handle_action(Action::Render).await?;
+
+        assert!(runner.app.is_rendered, "App should have been drawn.");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_handle_action_render_while_suspended() -> Result<()> {
+        let mut runner = setup()?;
+        runner.app.is_rendered = false;
+        runner.should_suspend = true;
+
+        runner.handle_action(Action::Render).await?;
+
+        assert!(
+            !runner.app.is_rendered,
+            "App should not be drawn while suspended."
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_reload_config() -> Result<()> {
+        let mut runner = setup()?;
+        let config = Config {
+            frame_rate: 60.0,
+            ..Config::default()
+        };
+        let warnings = vec![String::from("unknown key `colour_scheme`")];
+
+        runner.reload_config(Ok(LoadedConfig { config, warnings }))?;
 
-        assert_eq!(runner.app.is_rendered, true);
+        assert_eq!(runner.config.frame_rate, 60.0);
+        assert_eq!(
+            runner.app.config.map(|config| config.frame_rate),
+            Some(60.0),
+            "App should have received the reloaded config."
+        );
+        assert_eq!(
+            runner.action_bus.recv().await,
+            Some(Action::OpenOverlay(Overlay::Error(ErrorBox::new(
+                "Config Warning",
+                "unknown key `colour_scheme`"
+            ))))
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_reload_invalid_config() -> Result<()> {
+        let mut runner = setup()?;
+
+        runner.reload_config(Err(eyre!("config.toml:1:1: invalid type")))?;
+
+        assert_eq!(runner.config.frame_rate, Config::default().frame_rate);
+        assert!(
+            runner.app.config.is_none(),
+            "App should have kept its current config."
+        );
+        assert_eq!(
+            runner.action_bus.recv().await,
+            Some(Action::OpenOverlay(Overlay::Error(ErrorBox::new(
+                "Config Error",
+                "config.toml:1:1: invalid type\n\nKeeping the current settings."
+            ))))
+        );
 
         Ok(())
     }
@@ -297,43 +546,77 @@ mod tests {
     // @SECTION: Runner.run Tests
 
     #[tokio::test]
-    async fn test_run_call_app_init() -> Result<()> {
-        let mut runner = setup();
+    async fn test_run_exits_on_quit_event() -> Result<()> {
+        let mut runner = setup()?;
+        runner.event_loop.cancel();
 
-        tokio::select! {
-            _ = runner.run() => {},
-            _ = tokio::time::sleep(tokio::time::Duration::from_secs(1)) => {
-            },
-        }
+        timeout(Duration::from_secs(2), runner.run()).await??;
 
-        assert_eq!(runner.app.init_called, true);
+        assert!(runner.should_quit, "Runner should have quit.");
 
         Ok(())
     }
 
     #[tokio::test]
-    async fn test_run_register_action_handler() -> Result<()> {
-        let mut runner = setup();
+    async fn test_run_restores_and_saves_session() -> Result<()> {
+        let data_dir = TempDir::new("runner_session")?;
+        let session_store = SessionStore::new(&data_dir.path, Path::new("/workspace"));
+        let saved = Session {
+            selected_project: Some(String::from("api")),
+            ..Session::default()
+        };
+        session_store.save(&saved)?;
+
+        let mut runner = setup()?;
+        runner.set_session_store(session_store.clone());
+        runner.event_loop.cancel();
+
+        timeout(Duration::from_secs(2), runner.run()).await??;
+
+        assert_eq!(runner.app.restored_session, Some(saved));
+        assert_eq!(
+            session_store.load().focused_pane,
+            Some(Pane::Main),
+            "The session should have been saved on exit."
+        );
 
-        tokio::select! {
-            _ = runner.run() => {},
-            _ = tokio::time::sleep(tokio::time::Duration::from_secs(1)) => {
-            },
-        }
+        Ok(())
+    }
 
-        assert_eq!(runner.app.action_handler_tx.is_some(), true);
+    #[tokio::test]
+    async fn test_run_forwards_process_output() -> Result<()> {
+        let mut runner = setup()?;
+        let id = runner.process_handler.spawn(Command::new("true"))?;
+
+        let _ = timeout(Duration::from_millis(500), runner.run()).await;
+
+        assert_eq!(
+            runner.app.updated_with,
+            Some(Action::ProcessOutput(ProcessOutput {
+                id,
+                event: ProcessEvent::Exited(ExitStatus::Code(0))
+            }))
+        );
 
         Ok(())
     }
 
     #[tokio::test]
-    async fn test_run_should_exit_tui_on_quit() -> Result<()> {
-        let mut runner = setup();
+    async fn test_run_initializes_app() -> Result<()> {
+        let mut runner = setup()?;
         runner.should_quit = true;
 
-        if let Err(error) = timeout(Duration::from_secs(2), runner.run()).await {
-            panic!("Runner.run() failed to exit tui: {error:?}");
-        }
+        runner.run().await?;
+
+        assert!(runner.app.init_called, "App should have been initialized.");
+        assert!(
+            runner.app.action_handler_tx.is_some(),
+            "App should have received the action handler."
+        );
+        assert!(
+            runner.app.config.is_some(),
+            "App should have received the config."
+        );
 
         Ok(())
     }
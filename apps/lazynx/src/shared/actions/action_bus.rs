@@ -0,0 +1,104 @@
+use color_eyre::eyre::Result;
+use crossterm::event::Event as CrosstermEvent;
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
+
+use super::Action;
+use crate::shared::events::Event;
+
+/// The single channel every action in the application flows through.
+///
+/// Events are turned into actions with `ActionBus::from_event`, components
+/// dispatch follow-up actions through a cloned `sender`, and the `Runner`
+/// receives them one at a time. Every action other than `Render` and `Tick`
+/// is traced to the log as it is received.
+pub struct ActionBus {
+    rx: UnboundedReceiver<Action>,
+    tx: UnboundedSender<Action>,
+}
+
+impl Default for ActionBus {
+    fn default() -> Self {
+        let (tx, rx) = unbounded_channel();
+        return Self { rx, tx };
+    }
+}
+
+impl ActionBus {
+    /// Maps events that always produce the same action, regardless of which
+    /// component is focused. Any other event is left for the components to
+    /// handle.
+    pub fn from_event(event: &Event) -> Option<Action> {
+        return match event {
+            Event::Crossterm(CrosstermEvent::Resize(x, y)) => Some(Action::Resize { x: *x, y: *y }),
+            Event::Error(message) => Some(Action::Error(message.clone())),
+            Event::Init => Some(Action::Init),
+            Event::Quit => Some(Action::Quit),
+            Event::Render => Some(Action::Render),
+            Event::Suspend => Some(Action::Suspend),
+            Event::Tick => Some(Action::Tick),
+            _ => None,
+        };
+    }
+
+    /// Returns a sender that can be handed to components and background tasks.
+    pub fn sender(&self) -> UnboundedSender<Action> {
+        return self.tx.clone();
+    }
+
+    pub fn dispatch(&self, action: Action) -> Result<()> {
+        self.tx.send(action)?;
+        Ok(())
+    }
+
+    /// Waits for the next dispatched action.
+    pub async fn recv(&mut self) -> Option<Action> {
+        let action = self.rx.recv().await?;
+
+        if !matches!(action, Action::Render | Action::Tick) {
+            tracing::debug!("{action:?}");
+        }
+
+        return Some(action);
+    }
+}
+
+#[cfg(test)]
+mod action_bus_tests {
+    use super::ActionBus;
+
+    use color_eyre::eyre::Result;
+    use crossterm::event::{Event as CrosstermEvent, KeyCode, KeyEvent, KeyModifiers};
+    use pretty_assertions::assert_eq;
+    use test_case::test_case;
+
+    use crate::shared::{actions::Action, events::Event};
+
+    const KEY: KeyEvent = KeyEvent::new(KeyCode::Char('a'), KeyModifiers::empty());
+
+    #[test_case(Event::Init, Some(Action::Init); "Init")]
+    #[test_case(Event::Quit, Some(Action::Quit); "Quit")]
+    #[test_case(Event::Render, Some(Action::Render); "Render")]
+    #[test_case(Event::Suspend, Some(Action::Suspend); "Suspend")]
+    #[test_case(Event::Tick, Some(Action::Tick); "Tick")]
+    #[test_case(Event::Error(String::from("oops")), Some(Action::Error(String::from("oops"))); "Error")]
+    #[test_case(Event::Crossterm(CrosstermEvent::Resize(1, 2)), Some(Action::Resize { x: 1, y: 2 }); "Resize")]
+    #[test_case(Event::Crossterm(CrosstermEvent::Key(KEY)), None; "Key Events Are Left For Components")]
+    fn test_from_event(event: Event, expected: Option<Action>) {
+        let response = ActionBus::from_event(&event);
+
+        assert_eq!(response, expected, "Unexpected action for {event:?}");
+    }
+
+    #[tokio::test]
+    async fn test_recv_in_dispatch_order() -> Result<()> {
+        let mut bus = ActionBus::default();
+
+        bus.dispatch(Action::Init)?;
+        bus.sender().send(Action::Quit)?;
+
+        assert_eq!(bus.recv().await, Some(Action::Init));
+        assert_eq!(bus.recv().await, Some(Action::Quit));
+
+        Ok(())
+    }
+}
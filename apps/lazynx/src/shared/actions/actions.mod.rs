@@ -1 +1,5 @@
-pub mod actions;
+mod actions;
+pub use actions::Action;
+
+mod action_bus;
+pub use action_bus::ActionBus;
@@ -1,12 +1,61 @@
 use serde::{Deserialize, Serialize};
 use strum::Display;
 
+use crate::shared::{focus::Pane, overlay::Overlay, process::ProcessOutput};
+
 #[derive(Debug, Clone, PartialEq, Eq, Display, Serialize, Deserialize, Hash)]
 pub enum Action {
+    ClearScreen,
+    CloseOverlay,
+    CommandPalette,
+    /// An action the user already confirmed in a dialog, so it goes ahead
+    /// without asking again.
+    Confirmed(Box<Action>),
+    Doctor,
     Error(String),
+    FilterProjects,
+    Focus(Pane),
+    FocusNext,
+    FocusPrev,
+    GrowSidebar,
     Help,
     Init,
+    /// Selects the project and focuses the pane, running the target if one
+    /// is given. Used to start lazynx at a project from the command line.
+    OpenLink {
+        project: Option<String>,
+        pane: Option<Pane>,
+        target: Option<String>,
+        configuration: Option<String>,
+    },
+    OpenOverlay(Overlay),
+    ProcessOutput(ProcessOutput),
+    PromptSubmitted {
+        id: String,
+        value: String,
+    },
     Quit,
     Render,
-    Resize { x: u16, y: u16 },
+    Resize {
+        x: u16,
+        y: u16,
+    },
+    Resume,
+    RunCommand(String),
+    /// Runs the target selected in the targets pane.
+    RunSelectedTarget,
+    RunTarget {
+        project: String,
+        target: String,
+        #[serde(default)]
+        configuration: Option<String>,
+    },
+    ScrollDown,
+    ScrollUp,
+    SelectProject(String),
+    SelectNext,
+    SelectPrev,
+    ShrinkSidebar,
+    Suspend,
+    Tick,
 }
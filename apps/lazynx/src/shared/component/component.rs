@@ -0,0 +1,65 @@
+use color_eyre::eyre::Result;
+use ratatui::{layout::Rect, Frame};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::shared::{
+    actions::Action, config::Config, events::Event, keymap::KeyContext, process::ProcessHandler,
+    session::Session,
+};
+
+/// A self-contained piece of the UI.
+///
+/// Components receive events and actions from the `Runner`, may answer them
+/// with follow-up actions, and draw themselves into the area they are given.
+pub trait Component {
+    /// Registers the sender the component can use to dispatch actions of its
+    /// own (e.g. from background tasks).
+    fn register_action_handler(&mut self, _tx: UnboundedSender<Action>) -> Result<()> {
+        Ok(())
+    }
+
+    /// Registers the handler the component should spawn child processes with,
+    /// so their output reaches the `Runner` and they are terminated on exit.
+    fn register_process_handler(&mut self, _handler: ProcessHandler) -> Result<()> {
+        Ok(())
+    }
+
+    /// Registers the application configuration with the component.
+    fn register_config_handler(&mut self, _config: Config) -> Result<()> {
+        Ok(())
+    }
+
+    /// Initializes the component before the first frame is drawn.
+    fn init(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    /// Restores where the user left off in the last session, after `init`.
+    fn restore_session(&mut self, _session: &Session) -> Result<()> {
+        Ok(())
+    }
+
+    /// Records the state worth restoring on the next launch into the session.
+    fn save_session(&self, _session: &mut Session) {}
+
+    /// The context the component looks up key presses in, if it has its own.
+    /// Its bindings take precedence over the global ones.
+    fn key_context(&self) -> Option<KeyContext> {
+        return None;
+    }
+
+    /// Handles an event that was not already turned into an action by the
+    /// `Runner`, optionally returning an action to dispatch.
+    fn handle_events(&mut self, _event: Event) -> Option<Action> {
+        None
+    }
+
+    /// Updates the component's state with the given action, optionally
+    /// returning a follow-up action to dispatch.
+    fn update(&mut self, _action: Action) -> Result<Option<Action>> {
+        Ok(None)
+    }
+
+    /// Draws the component into the given area of the frame.
+    fn draw(&mut self, frame: &mut Frame, area: Rect);
+}
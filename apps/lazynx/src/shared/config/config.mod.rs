@@ -1,5 +1,21 @@
 mod config;
 pub use config::Config;
 
+mod default_config_template;
+pub use default_config_template::default_config_template;
+
+mod custom_command;
+pub use custom_command::CustomCommand;
+
+mod config_watcher;
+pub use config_watcher::ConfigWatcher;
+
+mod load_config;
+pub use load_config::{environment_source, load_config, LoadedConfig};
+
+mod locate_key;
+
+mod validate_config;
+
 #[path = "./utils/config.utils.mod.rs"]
 pub mod utils;
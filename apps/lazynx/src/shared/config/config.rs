@@ -1,26 +1,42 @@
-use std::path::PathBuf;
+use std::{collections::BTreeMap, path::PathBuf};
 
-use serde::{Deserialize, Serialize};
+use serde::{
+    de::{value::Error as ValueError, IntoDeserializer},
+    Deserialize, Deserializer, Serialize,
+};
 use serde_with::{serde_as, NoneAsEmptyString};
 use tracing::level_filters::LevelFilter;
 
-use super::utils::{default_config_dir, default_config_file, default_data_dir};
+use super::{
+    utils::{default_config_dir, default_config_file, default_data_dir},
+    CustomCommand,
+};
+use crate::shared::{
+    keymap::{KeyContext, Keymap},
+    theme::Theme,
+};
 
 /// Application configuration.
 ///
-/// This is the main configuration struct for the application.
+/// This is the main configuration struct for the application. Any setting
+/// missing from the loaded sources falls back to its default.
 #[serde_as]
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default)]
 pub struct Config {
     /// The directory to use for storing application data (logs etc.).
     pub data_dir: PathBuf,
 
-    /// The directory to use for storing application configuration (colors
-    /// etc.).
+    /// The directory the config file is looked for in, set through
+    /// `LAZYNX_CONFIG_HOME`. It can't be set from the config file, which is
+    /// found through it.
+    #[serde(skip)]
     pub config_home: PathBuf,
 
-    /// The directory to use for storing application configuration (colors
-    /// etc.).
+    /// The config file the settings were loaded from. Its format (JSON5,
+    /// JSON, YAML or TOML) is detected from the extension. It can't be set
+    /// from the config file itself.
+    #[serde(skip)]
     pub config_file: PathBuf,
 
     /// The log level to use. Valid values are: error, warn, info, debug, trace,
@@ -31,23 +47,82 @@ pub struct Config {
     /// The frame rate to use for rendering the application's UI and animation.
     pub frame_rate: f64,
 
+    /// The number of ticks per second used for updating the application's
+    /// state (spinners, timers, polling running tasks etc.).
+    pub tick_rate: f64,
+
     /// Enable/Disable mouse support.
     pub enable_mouse: bool,
 
     /// Enable/Disable clipboard support.
     pub enable_paste: bool,
+
+    /// Key bindings for each context, overriding the default ones, e.g.
+    /// `"<Ctrl-c>" = "Quit"` under `[keybindings.global]`.
+    #[serde(deserialize_with = "deserialize_keybindings")]
+    pub keybindings: BTreeMap<KeyContext, BTreeMap<String, String>>,
+
+    /// The default key bindings with the `keybindings` applied, built when
+    /// the config is loaded.
+    #[serde(skip)]
+    pub keymap: Keymap,
+
+    /// The colors the UI is drawn with.
+    pub theme: Theme,
+
+    /// Extra arguments passed to every `nx run` (e.g. `--skip-nx-cache`).
+    pub run_args: Vec<String>,
+
+    /// Shell commands that can be run from the command palette.
+    pub commands: Vec<CustomCommand>,
+
+    /// Projects left out of the projects list.
+    pub hidden_projects: Vec<String>,
+
+    /// Targets, written as `project:target`, listed first in the command
+    /// palette.
+    pub favorite_targets: Vec<String>,
 }
 
 impl Default for Config {
     fn default() -> Self {
         return Self {
+            commands: Vec::new(),
             config_file: default_config_file(),
             config_home: default_config_dir(),
             data_dir: default_data_dir(),
             enable_mouse: false,
             enable_paste: false,
+            favorite_targets: Vec::new(),
             frame_rate: 30.0,
+            hidden_projects: Vec::new(),
+            keybindings: BTreeMap::new(),
+            keymap: Keymap::default(),
             log_level: None,
+            run_args: Vec::new(),
+            theme: Theme::default(),
+            tick_rate: 4.0,
         };
     }
 }
+
+/// Reads the `[keybindings.<context>]` sections, leaving out the ones for
+/// unknown contexts. Those are warned about along with the other unknown keys
+/// instead of failing the whole config.
+fn deserialize_keybindings<'de, TDeserializer>(
+    deserializer: TDeserializer,
+) -> Result<BTreeMap<KeyContext, BTreeMap<String, String>>, TDeserializer::Error>
+where
+    TDeserializer: Deserializer<'de>,
+{
+    let sections = BTreeMap::<String, BTreeMap<String, String>>::deserialize(deserializer)?;
+
+    return Ok(sections
+        .into_iter()
+        .filter_map(|(name, section)| {
+            let context: Result<KeyContext, ValueError> =
+                KeyContext::deserialize(name.into_deserializer());
+            return context.ok().map(|context| (context, section));
+        })
+        .collect());
+}
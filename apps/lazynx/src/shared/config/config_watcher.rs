@@ -0,0 +1,282 @@
+use std::{
+    path::{self, Path, PathBuf},
+    sync::{Arc, Mutex, Weak},
+    time::Duration,
+};
+
+use color_eyre::eyre::{eyre, Result};
+use config::Source;
+use notify::{recommended_watcher, Event, RecommendedWatcher, RecursiveMode, Watcher};
+use tokio::{
+    sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender},
+    time::sleep,
+};
+
+use super::{load_config, LoadedConfig};
+
+/// How long to wait for further changes before reloading, since editors tend
+/// to save a file in several steps.
+const DEBOUNCE_DELAY: Duration = Duration::from_millis(100);
+
+/// Watches the config files and reloads the config whenever one changes.
+pub struct ConfigWatcher {
+    config_file: PathBuf,
+    overrides: Vec<Box<dyn Source + Send + Sync>>,
+    rx: UnboundedReceiver<()>,
+    workspace_config_file: Option<PathBuf>,
+    _watcher: Arc<Mutex<RecommendedWatcher>>,
+}
+
+impl ConfigWatcher {
+    /// Starts watching the user's and the workspace's config file. The
+    /// `overrides` are layered over the files on every reload, just like on
+    /// startup.
+    ///
+    /// A file that can't be watched is logged and skipped, so the other one
+    /// is still reloaded. A file in a missing directory is watched for
+    /// through the nearest directory that exists, until its own is created.
+    pub fn new<TSource>(
+        config_file: &Path,
+        workspace_config_file: Option<&Path>,
+        overrides: TSource,
+    ) -> Result<Self>
+    where
+        TSource: Source + Send + Sync + 'static,
+    {
+        let watched_files = [Some(config_file), workspace_config_file]
+            .into_iter()
+            .flatten()
+            .filter_map(|file| match resolve_path(file) {
+                Ok(file) => Some(file),
+                Err(error) => {
+                    tracing::warn!("Unable to watch {}: {error}", file.display());
+                    None
+                }
+            })
+            .collect::<Vec<PathBuf>>();
+
+        let (path_tx, path_rx) = unbounded_channel();
+        let files = watched_files.clone();
+        let watcher = recommended_watcher(move |event: notify::Result<Event>| {
+            let Ok(event) = event else {
+                return;
+            };
+
+            let is_change =
+                event.kind.is_create() || event.kind.is_modify() || event.kind.is_remove();
+            if !is_change {
+                return;
+            }
+
+            // Besides the config files themselves, the missing directories
+            // they are to be written in may have been created.
+            for path in event.paths {
+                if files.iter().any(|file| file.starts_with(&path)) {
+                    let _ = path_tx.send(path);
+                }
+            }
+        })?;
+        let watcher = Arc::new(Mutex::new(watcher));
+        watch_nearest_dirs(&watcher, &watched_files);
+
+        let (file_tx, file_rx) = unbounded_channel();
+        tokio::spawn(follow_dirs(
+            Arc::downgrade(&watcher),
+            watched_files,
+            path_rx,
+            file_tx,
+        ));
+        let (tx, rx) = unbounded_channel();
+        tokio::spawn(debounce(file_rx, tx));
+
+        return Ok(Self {
+            config_file: config_file.to_path_buf(),
+            overrides: vec![Box::new(overrides)],
+            rx,
+            workspace_config_file: workspace_config_file.map(Path::to_path_buf),
+            _watcher: watcher,
+        });
+    }
+
+    /// Waits for the config file to change, then reloads the config. Returns
+    /// `None` once the file is no longer watched.
+    ///
+    /// This is cancel safe, so no change is missed when it is used in
+    /// `tokio::select!`.
+    pub async fn changed(&mut self) -> Option<Result<LoadedConfig>> {
+        self.rx.recv().await?;
+
+        return Some(load_config(
+            &self.config_file,
+            self.workspace_config_file.as_deref(),
+            self.overrides.clone(),
+        ));
+    }
+}
+
+/// Resolves the directory of the file up to the nearest one that exists, so
+/// the path matches the ones reported for changes in it.
+fn resolve_path(file: &Path) -> Result<PathBuf> {
+    let file_name = file
+        .file_name()
+        .ok_or_else(|| eyre!("{} is not a file", file.display()))?;
+    let dir = match file.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => path::absolute(dir)?,
+        _ => path::absolute(".")?,
+    };
+    let existing = dir
+        .ancestors()
+        .find(|dir| dir.is_dir())
+        .ok_or_else(|| eyre!("None of the directories of {} exist", file.display()))?;
+
+    return Ok(existing
+        .canonicalize()?
+        .join(dir.strip_prefix(existing)?)
+        .join(file_name));
+}
+
+/// Watches the nearest directory that exists above each file. Editors often
+/// replace the file rather than writing to it, which a watch on the file
+/// itself would not survive.
+fn watch_nearest_dirs(watcher: &Mutex<RecommendedWatcher>, files: &[PathBuf]) {
+    let Ok(mut watcher) = watcher.lock() else {
+        return;
+    };
+
+    for file in files {
+        let Some(dir) = file.ancestors().skip(1).find(|dir| dir.is_dir()) else {
+            continue;
+        };
+
+        if let Err(error) = watcher.watch(dir, RecursiveMode::NonRecursive) {
+            tracing::warn!("Unable to watch {}: {error}", file.display());
+        }
+    }
+}
+
+/// Forwards the changes to the files, moving the watches down as the missing
+/// directories above them are created.
+async fn follow_dirs(
+    watcher: Weak<Mutex<RecommendedWatcher>>,
+    files: Vec<PathBuf>,
+    mut path_rx: UnboundedReceiver<PathBuf>,
+    file_tx: UnboundedSender<()>,
+) {
+    while let Some(path) = path_rx.recv().await {
+        let is_config_file = files.contains(&path);
+        if !is_config_file {
+            let Some(watcher) = watcher.upgrade() else {
+                return;
+            };
+            watch_nearest_dirs(&watcher, &files);
+        }
+
+        // The file may have been written before its directory was watched.
+        let is_written = files
+            .iter()
+            .any(|file| file.starts_with(&path) && file.exists());
+
+        if (is_config_file || is_written) && file_tx.send(()).is_err() {
+            return;
+        }
+    }
+}
+
+/// Forwards a single notification for each burst of file changes.
+async fn debounce(mut file_rx: UnboundedReceiver<()>, tx: UnboundedSender<()>) {
+    while file_rx.recv().await.is_some() {
+        sleep(DEBOUNCE_DELAY).await;
+        while file_rx.try_recv().is_ok() {}
+
+        if tx.send(()).is_err() {
+            return;
+        }
+    }
+}
+
+#[cfg(test)]
+mod config_watcher_tests {
+    use super::ConfigWatcher;
+
+    use std::fs;
+
+    use color_eyre::eyre::Result;
+    use pretty_assertions::assert_eq;
+    use test_case::test_case;
+    use test_utils::TempDir;
+    use tokio::time::{sleep, timeout, Duration};
+
+    use crate::cli::Cli;
+
+    #[tokio::test]
+    async fn test_reloads_on_change() -> Result<()> {
+        let dir = TempDir::new("config_watcher_change")?;
+        let config_file = dir.write("config.toml", "frame_rate = 30.0\n")?;
+        let mut watcher = ConfigWatcher::new(&config_file, None, Cli::default())?;
+
+        fs::write(&config_file, "frame_rate = 60.0\n")?;
+        let reloaded = timeout(Duration::from_secs(5), watcher.changed()).await?;
+
+        let config = reloaded.expect("The file should be watched.")?.config;
+        assert_eq!(config.frame_rate, 60.0);
+
+        Ok(())
+    }
+
+    #[test_case("missing_dir", "lazynx/config.toml"; "Missing Dir")]
+    #[test_case("missing_dirs", "config/lazynx/config.toml"; "Nested Missing Dirs")]
+    #[tokio::test]
+    async fn test_watches_missing_config_dir(name: &str, path: &str) -> Result<()> {
+        let dir = TempDir::new(&format!("config_watcher_{name}"))?;
+        let config_file = dir.join(path);
+        let mut watcher = ConfigWatcher::new(&config_file, None, Cli::default())?;
+        assert!(
+            !config_file.parent().unwrap().exists(),
+            "The config dir should not have been created."
+        );
+
+        dir.write(path, "frame_rate = 60.0\n")?;
+        let reloaded = timeout(Duration::from_secs(5), watcher.changed()).await?;
+
+        let config = reloaded.expect("The file should be watched.")?.config;
+        assert_eq!(config.frame_rate, 60.0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_watches_workspace_file_when_user_file_is_unwatchable() -> Result<()> {
+        let dir = TempDir::new("config_watcher_unwatchable")?;
+        // The user's config dir can never be created inside a file.
+        let blocker = dir.write("blocker", "")?;
+        let workspace_file = dir.write(".lazynx.toml", "frame_rate = 30.0\n")?;
+        let mut watcher = ConfigWatcher::new(
+            &blocker.join("config.toml"),
+            Some(&workspace_file),
+            Cli::default(),
+        )?;
+
+        fs::write(&workspace_file, "frame_rate = 60.0\n")?;
+        let reloaded = timeout(Duration::from_secs(5), watcher.changed()).await?;
+
+        let config = reloaded.expect("The file should be watched.")?.config;
+        assert_eq!(config.frame_rate, 60.0);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_ignores_other_files() -> Result<()> {
+        let dir = TempDir::new("config_watcher_other")?;
+        let mut watcher = ConfigWatcher::new(&dir.join("config.toml"), None, Cli::default())?;
+
+        fs::write(dir.join("notes.txt"), "not the config")?;
+        sleep(Duration::from_millis(50)).await;
+
+        let reloaded = timeout(Duration::from_millis(300), watcher.changed()).await;
+
+        assert!(reloaded.is_err(), "Only the config file should be watched.");
+
+        Ok(())
+    }
+}
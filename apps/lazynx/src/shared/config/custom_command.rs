@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+/// A shell command that can be run from the command palette, like a script
+/// the whole team uses.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CustomCommand {
+    /// The name shown in the command palette.
+    pub name: String,
+
+    /// The command line, run by `sh` at the root of the workspace.
+    pub command: String,
+}
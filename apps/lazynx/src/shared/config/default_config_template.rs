@@ -0,0 +1,149 @@
+use color_eyre::eyre::Result;
+use serde_json::Value;
+
+use super::Config;
+
+const HEADER: &str = "\
+# lazynx config file.
+#
+# Every setting is optional and commented out below with its default value.
+# Settings can also be set with LAZYNX_<SETTING> environment variables and
+# command line flags, which take precedence over this file. A workspace can
+# override them for everyone working in it with a .lazynx.toml at its root.
+";
+
+/// What each setting does, in the order they are written to a new config
+/// file. Tables come last, since TOML would otherwise file the settings after
+/// them under the table.
+const DESCRIPTIONS: [(&str, &str); 12] = [
+    ("log_level", "The log level: off, error, warn, info, debug or trace."),
+    ("frame_rate", "Frames rendered per second."),
+    (
+        "tick_rate",
+        "Ticks per second, used for updating state like spinners and timers.",
+    ),
+    ("enable_mouse", "Enable mouse support."),
+    ("enable_paste", "Enable pasting from the clipboard."),
+    ("data_dir", "Where logs and other application data are stored."),
+    (
+        "run_args",
+        "Extra arguments passed to every `nx run` (e.g. \"--skip-nx-cache\").",
+    ),
+    ("hidden_projects", "Projects left out of the projects list."),
+    (
+        "favorite_targets",
+        "Targets, written as \"project:target\", listed first in the command\npalette.",
+    ),
+    (
+        "commands",
+        "Shell commands that can be run from the command palette, e.g.\n\n  [[commands]]\n  name = \"Lint everything\"\n  command = \"npx nx run-many -t lint\"\n",
+    ),
+    (
+        "keybindings",
+        "Key bindings, overriding the default ones shown with `?`, for the\nglobal, main, workspace, projects or targets context. Keys are written like\n\"q\", \"<Ctrl-c>\", \"<Shift-g>\" or \"<F1>\", and actions like \"Quit\" or\n\"Focus Projects\", or \"None\" to unbind a key, e.g.\n\n  [keybindings.global]\n  \"q\" = \"Quit\"\n  \"<Ctrl-d>\" = \"None\"\n\nWhile a pane is focused, its bindings take precedence over the global\nones.",
+    ),
+    ("theme", "The colors the UI is drawn with, given by name (e.g. \"green\"),\nas a hex code (e.g. \"#00ff00\") or as an ANSI index (e.g. \"42\")."),
+];
+
+/// Writes a TOML config file documenting every setting, with the settings
+/// themselves commented out so the defaults keep applying until they are
+/// changed.
+pub fn default_config_template() -> Result<String> {
+    let defaults = serde_json::to_value(Config::default())?;
+    let mut template = String::from(HEADER);
+
+    for (key, description) in DESCRIPTIONS {
+        template.push('\n');
+        for line in description.lines() {
+            template.push_str(format!("# {line}").trim_end());
+            template.push('\n');
+        }
+
+        match &defaults[key] {
+            Value::Object(table) => {
+                template.push_str(&format!("# [{key}]\n"));
+                for (name, value) in table {
+                    template.push_str(&format!("# {name} = {value}\n"));
+                }
+            }
+            value => template.push_str(&format!("# {key} = {value}\n")),
+        }
+    }
+
+    return Ok(template);
+}
+
+#[cfg(test)]
+mod default_config_template_tests {
+    use super::default_config_template;
+
+    use color_eyre::eyre::Result;
+    use config::{File, Source};
+    use pretty_assertions::assert_eq;
+    use test_utils::TempDir;
+
+    use crate::shared::config::{validate_config::find_unknown_keys, Config};
+
+    /// Uncomments the settings, leaving the descriptions commented out.
+    fn uncomment_settings(template: &str) -> String {
+        return template
+            .lines()
+            .map(|line| {
+                let setting = line.strip_prefix("# ").unwrap_or_default();
+                let is_setting = setting.split_once(" = ").is_some_and(|(key, _)| {
+                    key.chars().all(|c| c.is_ascii_lowercase() || c == '_')
+                });
+                let is_table = setting.starts_with('[') && setting.ends_with(']');
+
+                match (is_setting && !setting.is_empty()) || is_table {
+                    true => setting,
+                    false => line,
+                }
+            })
+            .collect::<Vec<&str>>()
+            .join("\n");
+    }
+
+    #[test]
+    fn test_template_matches_defaults() -> Result<()> {
+        let dir = TempDir::new("default_config_template")?;
+        let path = dir.write(
+            "config.toml",
+            &uncomment_settings(&default_config_template()?),
+        )?;
+
+        let file = File::from(path.as_path());
+        let config: Config = config::Config::builder()
+            .add_source(file.clone())
+            .build()?
+            .try_deserialize()?;
+
+        assert_eq!(
+            serde_json::to_value(config)?,
+            serde_json::to_value(Config::default())?
+        );
+        assert_eq!(find_unknown_keys(&file.collect()?), Vec::<String>::new());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_template_documents_every_setting() -> Result<()> {
+        let template = default_config_template()?;
+        let defaults = serde_json::to_value(Config::default())?;
+
+        let undocumented: Vec<&String> = defaults
+            .as_object()
+            .unwrap()
+            .keys()
+            .filter(|key| {
+                !template.contains(&format!("# {key} = "))
+                    && !template.contains(&format!("# [{key}]"))
+            })
+            .collect();
+
+        assert_eq!(undocumented, Vec::<&String>::new());
+
+        Ok(())
+    }
+}
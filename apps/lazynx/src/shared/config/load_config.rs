@@ -0,0 +1,311 @@
+use std::path::Path;
+
+use color_eyre::eyre::Result;
+use config::{Environment, File, Source};
+
+use super::{
+    validate_config::{describe_error, find_unknown_keys, validate_values},
+    Config,
+};
+use crate::shared::keymap::Keymap;
+
+pub struct LoadedConfig {
+    pub config: Config,
+    /// Problems that did not stop the config from loading, like unknown keys.
+    pub warnings: Vec<String>,
+}
+
+/// Loads the configuration in layers, each one overriding the last: the
+/// defaults, the user's config file, the workspace's config file (if any),
+/// `LAZYNX_*` environment variables and finally the `overrides` (i.e. the
+/// command line flags).
+///
+/// Errors point at the offending key, including its line and column when it
+/// was set in a config file. The key bindings are checked for conflicts here
+/// too, so a broken keymap never reaches the TUI.
+pub fn load_config<TSource>(
+    config_file: &Path,
+    workspace_config_file: Option<&Path>,
+    overrides: TSource,
+) -> Result<LoadedConfig>
+where
+    TSource: Source + Send + Sync + 'static,
+{
+    return load_config_with_environment(
+        config_file,
+        workspace_config_file,
+        environment_source(),
+        overrides,
+    );
+}
+
+/// The `LAZYNX_*` environment variables as a config source, parsing numbers
+/// and booleans out of them.
+pub fn environment_source() -> Environment {
+    return Environment::with_prefix("LAZYNX").try_parsing(true);
+}
+
+/// Loads the configuration like `load_config`, reading the `LAZYNX_*`
+/// variables from the given environment.
+fn load_config_with_environment<TSource>(
+    config_file: &Path,
+    workspace_config_file: Option<&Path>,
+    environment: Environment,
+    overrides: TSource,
+) -> Result<LoadedConfig>
+where
+    TSource: Source + Send + Sync + 'static,
+{
+    let files: Vec<_> = [Some(config_file), workspace_config_file]
+        .into_iter()
+        .flatten()
+        .map(|path| File::from(path).required(false))
+        .collect();
+
+    let mut warnings = Vec::new();
+    for file in &files {
+        let file_settings = file.collect().map_err(describe_error)?;
+        warnings.extend(find_unknown_keys(&file_settings));
+    }
+
+    let settings = config::Config::builder()
+        .add_source(files)
+        .add_source(environment)
+        .add_source(overrides)
+        .build()
+        .map_err(describe_error)?;
+
+    validate_values(&settings)?;
+
+    let mut config: Config = settings.try_deserialize().map_err(describe_error)?;
+    config.config_file = config_file.to_path_buf();
+    config.keymap = Keymap::default().with_keybindings(&config.keybindings)?;
+
+    return Ok(LoadedConfig { config, warnings });
+}
+
+#[cfg(test)]
+mod load_config_tests {
+    use super::{environment_source, load_config, load_config_with_environment};
+
+    use std::path::PathBuf;
+
+    use color_eyre::eyre::Result;
+    use config::{Map, Source, Value};
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use pretty_assertions::assert_eq;
+    use test_case::test_case;
+    use test_utils::TempDir;
+    use tracing::level_filters::LevelFilter;
+
+    use crate::shared::{actions::Action, keymap::KeyContext};
+
+    #[derive(Clone, Debug, Default)]
+    struct Overrides(Map<String, Value>);
+
+    impl Source for Overrides {
+        fn clone_into_box(&self) -> Box<dyn Source + Send + Sync> {
+            return Box::new(self.clone());
+        }
+
+        fn collect(&self) -> Result<Map<String, Value>, config::ConfigError> {
+            return Ok(self.0.clone());
+        }
+    }
+
+    #[test]
+    fn test_defaults_without_config_file() -> Result<()> {
+        let config = load_config(
+            &PathBuf::from("/missing/config.toml"),
+            None,
+            Overrides::default(),
+        )?
+        .config;
+
+        assert_eq!(config.frame_rate, 30.0);
+        assert!(!config.enable_mouse, "Mouse should be disabled by default.");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_layers_override_each_other() -> Result<()> {
+        let dir = TempDir::new("load_config_layers")?;
+        let path = dir.write(
+            "config.toml",
+            "frame_rate = 60.0\nenable_mouse = true\nlog_level = \"warn\"\ntick_rate = 2.0\n",
+        )?;
+        let environment = environment_source().source(Some(Map::from([(
+            String::from("LAZYNX_TICK_RATE"),
+            String::from("8"),
+        )])));
+        let overrides = Overrides(Map::from([(
+            String::from("frame_rate"),
+            Value::from(120.0),
+        )]));
+
+        let config = load_config_with_environment(&path, None, environment, overrides)?.config;
+
+        assert_eq!(config.frame_rate, 120.0, "Overrides should win.");
+        assert_eq!(config.tick_rate, 8.0, "Environment should beat the file.");
+        assert!(config.enable_mouse, "File should beat the defaults.");
+        assert!(!config.enable_paste, "Unset values should be defaults.");
+        assert_eq!(config.log_level, Some(LevelFilter::WARN));
+        assert_eq!(config.config_file, path);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_workspace_config_overrides_user_config() -> Result<()> {
+        let dir = TempDir::new("load_config_workspace")?;
+        let user_file = dir.write(
+            "config.toml",
+            "frame_rate = 60.0\nhidden_projects = [\"legacy\"]\n",
+        )?;
+        let workspace_file = dir.write(
+            ".lazynx.json5",
+            "{\n  hidden_projects: ['e2e'],\n  run_args: ['--verbose'],\n  colour: 'red',\n}",
+        )?;
+
+        let loaded = load_config(&user_file, Some(&workspace_file), Overrides::default())?;
+
+        assert_eq!(loaded.config.frame_rate, 60.0);
+        assert_eq!(loaded.config.hidden_projects, vec![String::from("e2e")]);
+        assert_eq!(loaded.config.run_args, vec![String::from("--verbose")]);
+        assert_eq!(loaded.config.config_file, user_file);
+        assert_eq!(loaded.warnings.len(), 1);
+        assert!(
+            loaded.warnings[0].starts_with(&format!("{}:4:3:", workspace_file.display())),
+            "{}",
+            loaded.warnings[0]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_type_points_at_key() -> Result<()> {
+        let dir = TempDir::new("load_config_invalid_type")?;
+        let path = dir.write(
+            "config.toml",
+            "enable_mouse = true\nframe_rate = \"fast\"\n",
+        )?;
+
+        let error = load_config(&path, None, Overrides::default())
+            .err()
+            .map(|error| error.to_string());
+
+        assert_eq!(
+            error,
+            Some(format!(
+                "{}:2:1: invalid type for `frame_rate`: expected a floating point, found string \"fast\".",
+                path.display()
+            ))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_config_file_cannot_be_set() -> Result<()> {
+        let dir = TempDir::new("load_config_config_file")?;
+        let path = dir.write("config.toml", "config_file = \"other.toml\"\n")?;
+
+        let loaded = load_config(&path, None, Overrides::default())?;
+
+        assert_eq!(loaded.warnings.len(), 1);
+        assert!(loaded.warnings[0].contains("unknown key `config_file`"));
+        assert_eq!(loaded.config.config_file, path);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_unknown_keys_are_warnings() -> Result<()> {
+        let dir = TempDir::new("load_config_unknown")?;
+        let path = dir.write(
+            "config.yaml",
+            "colour_scheme: dark\nkeybindings:\n  globl:\n    q: Quit\n",
+        )?;
+
+        let loaded = load_config(&path, None, Overrides::default())?;
+
+        assert_eq!(loaded.warnings.len(), 2);
+        assert!(loaded.warnings[0].contains("unknown key `colour_scheme`"));
+        assert!(loaded.warnings[1].contains("unknown key `keybindings.globl`"));
+        assert!(
+            loaded.config.keybindings.is_empty(),
+            "The unknown context should have been left out."
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_keybindings() -> Result<()> {
+        let dir = TempDir::new("load_config_keybindings")?;
+        let path = dir.write(
+            "config.toml",
+            "[keybindings.global]\n\"<Ctrl-c>\" = \"Help\"\n\"<Shift-q>\" = \"Quit\"\n",
+        )?;
+
+        let config = load_config(&path, None, Overrides::default())?.config;
+
+        let key = |code, modifiers| KeyEvent::new(code, modifiers);
+        let keymap = config.keymap;
+        assert_eq!(
+            keymap.action_for(
+                KeyContext::Global,
+                &key(KeyCode::Char('c'), KeyModifiers::CONTROL)
+            ),
+            Some(Action::Help)
+        );
+        assert_eq!(
+            keymap.action_for(
+                KeyContext::Global,
+                &key(KeyCode::Char('Q'), KeyModifiers::SHIFT)
+            ),
+            Some(Action::Quit)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_conflicting_keybindings_fail() -> Result<()> {
+        let dir = TempDir::new("load_config_keybinding_conflict")?;
+        let path = dir.write(
+            "config.toml",
+            "[keybindings.projects]\n\"<c-j>\" = \"SelectNext\"\n\"<ctrl-j>\" = \"SelectPrev\"\n",
+        )?;
+
+        let error = load_config(&path, None, Overrides::default())
+            .err()
+            .map(|error| error.to_string());
+
+        assert_eq!(
+            error,
+            Some(String::from(
+                "`<c-j>` and `<ctrl-j>` bind the same key in [keybindings.projects]."
+            ))
+        );
+
+        Ok(())
+    }
+
+    #[test_case("config.json5", "{\n  // Match Nx's own config files.\n  frame_rate: 60,\n}"; "JSON5")]
+    #[test_case("config.json", r#"{ "frame_rate": 60 }"#; "JSON")]
+    #[test_case("config.yaml", "frame_rate: 60"; "YAML")]
+    #[test_case("config.toml", "frame_rate = 60"; "TOML")]
+    fn test_file_formats(file_name: &str, contents: &str) -> Result<()> {
+        let dir = TempDir::new(&format!("load_config_format_{file_name}"))?;
+        let path = dir.write(file_name, contents)?;
+
+        let config = load_config(&path, None, Overrides::default())?.config;
+
+        assert_eq!(config.frame_rate, 60.0);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,89 @@
+/// Finds the line and column (both starting at 1) where the key is first set
+/// in a config file's contents. A nested key, e.g. `keybindings.projects.q`,
+/// is looked for inside its parent table, which is found the same way.
+///
+/// This works the same for every supported format, since each of them writes
+/// a key as its name, optionally quoted, followed by `=` or `:`, or by `]` for
+/// a TOML table header. A parent's key may also be followed by `.` in TOML,
+/// e.g. `[keybindings.projects]`, and its children always come after it.
+pub fn locate_key(contents: &str, key: &str) -> Option<(usize, usize)> {
+    let lines: Vec<&str> = contents.lines().collect();
+    let segments: Vec<&str> = key
+        .split('.')
+        .map(|segment| segment.split('[').next().unwrap_or(segment))
+        .collect();
+
+    let mut start = (0, 0);
+    let mut position = None;
+    for (index, segment) in segments.iter().enumerate() {
+        let is_parent = index + 1 < segments.len();
+        let (line, column) = find_segment(&lines, segment, start, is_parent)?;
+
+        start = (line, column + segment.len());
+        position = Some((line + 1, column + 1));
+    }
+
+    return position;
+}
+
+/// Finds where one segment of a key is set, starting at the given line and
+/// column (both starting at 0).
+fn find_segment(
+    lines: &[&str],
+    segment: &str,
+    (start_line, start_column): (usize, usize),
+    is_parent: bool,
+) -> Option<(usize, usize)> {
+    let is_identifier = |char: char| char.is_alphanumeric() || char == '_' || char == '-';
+    let assignments: &[char] = match is_parent {
+        true => &['=', ':', ']', '.'],
+        false => &['=', ':', ']'],
+    };
+
+    for (index, line) in lines.iter().enumerate().skip(start_line) {
+        let offset = if index == start_line { start_column } else { 0 };
+
+        for (column, _) in line[offset..].match_indices(segment) {
+            let column = offset + column;
+            let before = line[..column].trim_end_matches(['"', '\'']);
+            let after = line[column + segment.len()..].trim_start_matches(['"', '\'']);
+
+            let is_whole_key =
+                !before.ends_with(is_identifier) && !after.starts_with(is_identifier);
+            let is_assigned = after.trim_start().starts_with(assignments);
+
+            if is_whole_key && is_assigned {
+                return Some((index, column));
+            }
+        }
+    }
+
+    return None;
+}
+
+#[cfg(test)]
+mod locate_key_tests {
+    use super::locate_key;
+
+    use pretty_assertions::assert_eq;
+    use test_case::test_case;
+
+    #[test_case("tick_rate = 2\nframe_rate = 60", Some((2, 1)); "TOML")]
+    #[test_case("{\n  \"frame_rate\": 60\n}", Some((2, 4)); "JSON")]
+    #[test_case("{\n  frame_rate: 60,\n}", Some((2, 3)); "JSON5")]
+    #[test_case("[keymap.frame_rate]\nq = 1", Some((1, 9)); "TOML Table")]
+    #[test_case("max_frame_rate: 60\nframe_rate: 60", Some((2, 1)); "Ignores Longer Keys")]
+    #[test_case("title = \"frame_rate\"", None; "Ignores Values")]
+    fn test_locate_key(contents: &str, expected: Option<(usize, usize)>) {
+        assert_eq!(locate_key(contents, "frame_rate"), expected);
+    }
+
+    #[test_case("[keybindings.global]\nq = \"Quit\"\n\n[keybindings.projects]\nq = \"Help\"", Some((5, 1)); "TOML Table")]
+    #[test_case("keybindings.global.q = \"Quit\"\nkeybindings.projects.q = \"Help\"", Some((2, 22)); "TOML Dotted Keys")]
+    #[test_case("keybindings:\n  global:\n    q: Quit\n  projects:\n    q: Help", Some((5, 5)); "YAML")]
+    #[test_case("{\n  \"keybindings\": {\n    \"global\": { \"q\": \"Quit\" },\n    \"projects\": { \"q\": \"Help\" }\n  }\n}", Some((4, 20)); "JSON")]
+    #[test_case("[keybindings.global]\nq = \"Quit\"", None; "Missing Parent")]
+    fn test_locate_nested_key(contents: &str, expected: Option<(usize, usize)>) {
+        assert_eq!(locate_key(contents, "keybindings.projects.q"), expected);
+    }
+}
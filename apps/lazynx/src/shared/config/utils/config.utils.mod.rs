@@ -7,5 +7,14 @@ pub use default_config_file::default_config_file;
 mod default_data_dir;
 pub use default_data_dir::default_data_dir;
 
+mod find_config_file;
+pub use find_config_file::find_config_file;
+
+mod find_one_file;
+use find_one_file::find_one_file;
+
+mod find_workspace_config_file;
+pub use find_workspace_config_file::find_workspace_config_file;
+
 mod project_dirs;
 pub use project_dirs::project_dirs;
@@ -1,8 +1,10 @@
 use std::path::PathBuf;
 
-use super::default_config_dir;
+use super::{default_config_dir, find_config_file};
 
 /// Returns the path to the default configuration file.
 pub fn default_config_file() -> PathBuf {
-    return default_config_dir().join("config.toml");
+    let config_dir = default_config_dir();
+
+    return find_config_file(&config_dir).unwrap_or_else(|_| config_dir.join("config.toml"));
 }
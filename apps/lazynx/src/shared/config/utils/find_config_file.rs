@@ -0,0 +1,68 @@
+use std::path::{Path, PathBuf};
+
+use color_eyre::eyre::Result;
+
+use super::find_one_file;
+
+/// The config file names that are picked up, in the order they are listed in
+/// errors. The format is detected from the extension.
+pub const CONFIG_FILE_NAMES: [&str; 4] =
+    ["config.json5", "config.json", "config.yaml", "config.toml"];
+
+/// Returns the config file in the directory, or `config.toml` when there is
+/// none yet.
+///
+/// It is an error for the directory to hold more than one config file, since
+/// it would be unclear which one wins.
+pub fn find_config_file(dir: &Path) -> Result<PathBuf> {
+    return find_one_file(dir, &CONFIG_FILE_NAMES, "config.toml");
+}
+
+#[cfg(test)]
+mod find_config_file_tests {
+    use super::find_config_file;
+
+    use color_eyre::eyre::Result;
+    use pretty_assertions::assert_eq;
+    use test_utils::TempDir;
+
+    fn setup(name: &str, files: &[&str]) -> Result<TempDir> {
+        let dir = TempDir::new(name)?;
+
+        for file in files {
+            dir.write(file, "")?;
+        }
+
+        return Ok(dir);
+    }
+
+    #[test]
+    fn test_defaults_to_toml() -> Result<()> {
+        let dir = setup("find_config_file_none", &[])?;
+
+        assert_eq!(find_config_file(&dir.path)?, dir.join("config.toml"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_finds_other_formats() -> Result<()> {
+        let dir = setup("find_config_file_json5", &["config.json5"])?;
+
+        assert_eq!(find_config_file(&dir.path)?, dir.join("config.json5"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_several_config_files() -> Result<()> {
+        let dir = setup("find_config_file_several", &["config.yaml", "config.toml"])?;
+
+        let error = find_config_file(&dir.path).unwrap_err().to_string();
+
+        assert!(error.contains("config.yaml"), "{error}");
+        assert!(error.contains("config.toml"), "{error}");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,32 @@
+use std::path::{Path, PathBuf};
+
+use color_eyre::eyre::{bail, Result};
+
+/// Returns whichever of the named files exists in the directory, or
+/// `default_name` when none of them does.
+///
+/// It is an error for more than one of them to exist, since it would be
+/// unclear which one wins.
+pub fn find_one_file(dir: &Path, names: &[&str], default_name: &str) -> Result<PathBuf> {
+    let found: Vec<PathBuf> = names
+        .iter()
+        .map(|name| dir.join(name))
+        .filter(|path| path.is_file())
+        .collect();
+
+    return match found.as_slice() {
+        [] => Ok(dir.join(default_name)),
+        [path] => Ok(path.clone()),
+        paths => {
+            let paths: Vec<String> = paths
+                .iter()
+                .map(|path| format!("  {}", path.display()))
+                .collect();
+
+            bail!(
+                "Found more than one config file:\n{}\nPlease keep only one of them.",
+                paths.join("\n")
+            )
+        }
+    };
+}
@@ -0,0 +1,41 @@
+use std::path::{Path, PathBuf};
+
+use color_eyre::eyre::Result;
+
+use super::find_one_file;
+
+/// The config file names that are picked up at the root of an Nx workspace.
+pub const WORKSPACE_CONFIG_FILE_NAMES: [&str; 2] = [".lazynx.json5", ".lazynx.toml"];
+
+/// Returns the workspace's config file, or `.lazynx.toml` when there is none
+/// yet. Teams can commit it to share settings across the workspace.
+pub fn find_workspace_config_file(workspace_root: &Path) -> Result<PathBuf> {
+    return find_one_file(workspace_root, &WORKSPACE_CONFIG_FILE_NAMES, ".lazynx.toml");
+}
+
+#[cfg(test)]
+mod find_workspace_config_file_tests {
+    use super::find_workspace_config_file;
+
+    use color_eyre::eyre::Result;
+    use pretty_assertions::assert_eq;
+    use test_utils::TempDir;
+
+    #[test]
+    fn test_finds_json5() -> Result<()> {
+        let dir = TempDir::new("find_workspace_config_file")?;
+
+        assert_eq!(
+            find_workspace_config_file(&dir.path)?,
+            dir.join(".lazynx.toml")
+        );
+
+        dir.write(".lazynx.json5", "{}")?;
+        assert_eq!(
+            find_workspace_config_file(&dir.path)?,
+            dir.join(".lazynx.json5")
+        );
+
+        Ok(())
+    }
+}
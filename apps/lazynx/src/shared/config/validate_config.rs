@@ -0,0 +1,269 @@
+use std::{collections::BTreeMap, fs, path::PathBuf};
+
+use color_eyre::eyre::{eyre, Report, Result};
+use config::{ConfigError, Map, Source, Value, ValueKind};
+use strum::IntoEnumIterator;
+
+use super::{locate_key::locate_key, Config};
+use crate::shared::keymap::KeyContext;
+
+/// Settings that only accept one of a fixed set of values.
+const VALID_VALUES: [(&str, &[&str]); 1] = [(
+    "log_level",
+    &["off", "error", "warn", "info", "debug", "trace"],
+)];
+
+/// Settings that must be a positive number, since they are turned into
+/// intervals.
+const POSITIVE_NUMBERS: [&str; 2] = ["frame_rate", "tick_rate"];
+
+/// The shape of the config: every key it understands, with the tables it
+/// nests. Each key binding context is filled in, since the defaults have none.
+fn known_shape() -> serde_json::Value {
+    let config = Config {
+        keybindings: KeyContext::iter()
+            .map(|context| (context, BTreeMap::new()))
+            .collect(),
+        ..Config::default()
+    };
+
+    return serde_json::to_value(config).unwrap_or_default();
+}
+
+/// Describes where a setting came from, pointing at the line and column of
+/// the key when it was read from a config file.
+fn format_location(origin: Option<&str>, key: &str) -> String {
+    return match origin {
+        None => String::from("command line"),
+        Some("the environment") => {
+            let name = key.split('.').next_back().unwrap_or(key);
+            let name = name.split('[').next().unwrap_or(name);
+            format!("environment variable LAZYNX_{}", name.to_uppercase())
+        }
+        Some(origin) => {
+            // The origin of a file's settings is its path relative to the
+            // working directory.
+            let config_file = fs::canonicalize(origin).unwrap_or_else(|_| PathBuf::from(origin));
+            let position = fs::read_to_string(&config_file)
+                .ok()
+                .and_then(|contents| locate_key(&contents, key));
+
+            match position {
+                Some((line, column)) => format!("{}:{line}:{column}", config_file.display()),
+                None => config_file.display().to_string(),
+            }
+        }
+    };
+}
+
+/// Warns about every key in the config file that lazynx does not know,
+/// including the ones nested in its tables (e.g. `[theme]`), since they would
+/// otherwise be silently ignored.
+pub fn find_unknown_keys(file: &Map<String, Value>) -> Vec<String> {
+    let mut warnings = Vec::new();
+    if let serde_json::Value::Object(known) = known_shape() {
+        find_unknown_keys_in(None, file, &known, &mut warnings);
+    }
+
+    return warnings;
+}
+
+fn find_unknown_keys_in(
+    table: Option<&str>,
+    file: &Map<String, Value>,
+    known: &serde_json::Map<String, serde_json::Value>,
+    warnings: &mut Vec<String>,
+) {
+    let mut keys: Vec<(&String, &Value)> = file.iter().collect();
+    keys.sort_by_key(|(key, _)| *key);
+
+    for (key, value) in keys {
+        let path = match table {
+            Some(table) => format!("{table}.{key}"),
+            None => key.clone(),
+        };
+
+        match (known.get(key), &value.kind) {
+            (None, _) => warnings.push(format!(
+                "{}: unknown key `{path}`. Valid keys are: {}.",
+                format_location(value.origin(), &path),
+                known.keys().cloned().collect::<Vec<_>>().join(", ")
+            )),
+            // Tables without known keys, like the bindings of a context, take
+            // any key.
+            (Some(serde_json::Value::Object(nested)), ValueKind::Table(values))
+                if !nested.is_empty() =>
+            {
+                find_unknown_keys_in(Some(&path), values, nested, warnings);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Checks the settings that only accept a fixed set of values or positive
+/// numbers.
+pub fn validate_values(settings: &config::Config) -> Result<()> {
+    let values = settings.collect()?;
+
+    for key in POSITIVE_NUMBERS {
+        let Some(value) = values.get(key) else {
+            continue;
+        };
+
+        // Values that aren't numbers at all are reported when deserializing.
+        let Ok(number) = value.clone().into_float() else {
+            continue;
+        };
+        if number.is_finite() && number > 0.0 {
+            continue;
+        }
+
+        return Err(eyre!(
+            "{}: invalid value \"{value}\" for `{key}`. It must be a positive number.",
+            format_location(value.origin(), key)
+        ));
+    }
+
+    for (key, valid_values) in VALID_VALUES {
+        let Some(value) = values.get(key) else {
+            continue;
+        };
+
+        let text = value.to_string().to_lowercase();
+        if text.is_empty() || valid_values.contains(&text.as_str()) {
+            continue;
+        }
+
+        return Err(eyre!(
+            "{}: invalid value \"{value}\" for `{key}`. Valid values are: {}.",
+            format_location(value.origin(), key),
+            valid_values.join(", ")
+        ));
+    }
+
+    Ok(())
+}
+
+/// Turns an error from loading the config into a message naming where the
+/// offending setting came from.
+pub fn describe_error(error: ConfigError) -> Report {
+    let ConfigError::Type {
+        origin: origin @ Some(_),
+        unexpected,
+        expected,
+        key: Some(key),
+    } = &error
+    else {
+        return eyre!(error);
+    };
+
+    return eyre!(
+        "{}: invalid type for `{key}`: expected {expected}, found {unexpected}.",
+        format_location(origin.as_deref(), key)
+    );
+}
+
+#[cfg(test)]
+mod validate_config_tests {
+    use super::{find_unknown_keys, validate_values};
+
+    use color_eyre::eyre::Result;
+    use config::{File, Source};
+    use pretty_assertions::assert_eq;
+    use test_case::test_case;
+    use test_utils::TempDir;
+
+    #[test]
+    fn test_unknown_keys() -> Result<()> {
+        let dir = TempDir::new("validate_unknown")?;
+        let path = dir.write("config.toml", "tick_rate = 2.0\nframe_rat = 60\n")?;
+        let file = File::from(path.as_path()).collect()?;
+
+        let warnings = find_unknown_keys(&file);
+
+        assert_eq!(warnings.len(), 1);
+        assert!(
+            warnings[0].starts_with(&format!("{}:2:1: unknown key `frame_rat`.", path.display())),
+            "{}",
+            warnings[0]
+        );
+        assert!(warnings[0].contains("frame_rate"), "{}", warnings[0]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_unknown_nested_keys() -> Result<()> {
+        let dir = TempDir::new("validate_unknown_nested")?;
+        let path = dir.write(
+            "config.toml",
+            "[theme]\nacent = \"red\"\n\n[keybindings.globl]\nq = \"Quit\"\n\n[keybindings.main]\nx = \"Quit\"\n",
+        )?;
+        let file = File::from(path.as_path()).collect()?;
+
+        let warnings = find_unknown_keys(&file);
+
+        assert_eq!(
+            warnings,
+            vec![
+                format!(
+                    "{}:4:14: unknown key `keybindings.globl`. Valid keys are: global, main, projects, targets, workspace.",
+                    path.display()
+                ),
+                format!(
+                    "{}:2:1: unknown key `theme.acent`. Valid keys are: accent, error, highlight, muted.",
+                    path.display()
+                ),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_log_level() -> Result<()> {
+        let dir = TempDir::new("validate_log_level")?;
+        let path = dir.write("config.toml", "\nlog_level = \"loud\"\n")?;
+        let settings = config::Config::builder()
+            .add_source(File::from(path.as_path()))
+            .build()?;
+
+        let error = validate_values(&settings).unwrap_err().to_string();
+
+        assert_eq!(
+            error,
+            format!(
+                "{}:2:1: invalid value \"loud\" for `log_level`. Valid values are: off, error, warn, info, debug, trace.",
+                path.display()
+            )
+        );
+
+        Ok(())
+    }
+
+    #[test_case("zero", "frame_rate = 0.0"; "Zero")]
+    #[test_case("negative", "frame_rate = -30.0"; "Negative")]
+    #[test_case("nan", "frame_rate = nan"; "Not A Number")]
+    #[test_case("infinite", "frame_rate = inf"; "Infinite")]
+    fn test_invalid_frame_rate(name: &str, contents: &str) -> Result<()> {
+        let dir = TempDir::new(&format!("validate_frame_rate_{name}"))?;
+        let path = dir.write("config.toml", &format!("tick_rate = 4.0\n{contents}\n"))?;
+        let settings = config::Config::builder()
+            .add_source(File::from(path.as_path()))
+            .build()?;
+
+        let error = validate_values(&settings).unwrap_err().to_string();
+
+        assert!(
+            error.starts_with(&format!("{}:2:1: invalid value", path.display())),
+            "{error}"
+        );
+        assert!(
+            error.ends_with("for `frame_rate`. It must be a positive number."),
+            "{error}"
+        );
+
+        Ok(())
+    }
+}
@@ -1,13 +1,10 @@
-use color_eyre::{config::EyreHook, eyre};
+use color_eyre::config::EyreHook;
 
-use crate::shared::tui::TuiRunner;
-
-pub fn install_eyre_hook(tui: TuiRunner, eyre_hook: EyreHook) -> color_eyre::Result<()> {
-    let tui = tui.clone();
-    let eyre_hook = eyre_hook.into_eyre_hook();
-    eyre::set_hook(Box::new(move |error| {
-        tui.restore().unwrap();
-        eyre_hook(error)
-    }))?;
+/// Unlike the panic hook, the eyre hook leaves the terminal alone. Reports are
+/// also created for errors the app recovers from (e.g. reloading an invalid
+/// config), so the `Runner` restores the terminal itself before an error ends
+/// the app.
+pub fn install_eyre_hook(eyre_hook: EyreHook) -> color_eyre::Result<()> {
+    eyre_hook.install()?;
     Ok(())
 }
@@ -27,7 +27,7 @@ pub fn install_hooks(tui: TuiRunner) -> Result<()> {
     }
 
     install_color_eyre_panic_hook(tui, panic_hook);
-    install_eyre_hook(tui, eyre_hook)?;
+    install_eyre_hook(eyre_hook)?;
 
     Ok(())
 }
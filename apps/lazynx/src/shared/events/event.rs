@@ -8,5 +8,6 @@ pub enum Event {
     Init,
     Quit,
     Render,
+    Suspend,
     Tick,
 }
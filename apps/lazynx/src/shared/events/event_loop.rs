@@ -1,20 +1,41 @@
-use std::{io::Error, pin::Pin, time::Duration};
+use std::{
+    future,
+    io::Error,
+    pin::Pin,
+    sync::{Arc, OnceLock},
+    time::Duration,
+};
 
 use crossterm::event::{Event as CrosstermEvent, KeyEventKind};
 use futures::Stream;
+use signal_hook::consts::SIGTSTP;
 use tokio::{
-    sync::mpsc::{self, error::TryRecvError, UnboundedReceiver, UnboundedSender},
+    signal::unix::{signal, Signal, SignalKind},
+    sync::{
+        mpsc::{self, UnboundedReceiver, UnboundedSender},
+        watch,
+    },
     time::{self, Interval},
 };
 use tokio_stream::StreamExt;
 use tokio_util::sync::CancellationToken;
 
-use super::Event;
+use super::{watch_exit_signals::watch_exit_signals, Event};
+
+/// How many ticks and frames happen per second.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Rates {
+    tick_rate: f64,
+    frame_rate: f64,
+}
 
 struct EventLoop<TEvents: Stream<Item = Result<CrosstermEvent, Error>> + Send + 'static> {
     cancellation_token: CancellationToken,
     terminal_events: Pin<Box<TEvents>>,
+    rates_rx: watch::Receiver<Rates>,
     render_interval: Interval,
+    suspend_signal: Option<Signal>,
+    tick_interval: Interval,
     tx: UnboundedSender<Event>,
 }
 
@@ -22,26 +43,45 @@ const ERROR_MESSAGE: &str = "Error reading terminal events";
 
 impl<TEvents: Stream<Item = Result<CrosstermEvent, Error>> + Send + 'static> EventLoop<TEvents> {
     fn new(
-        frame_rate: f64,
+        rates_rx: watch::Receiver<Rates>,
         terminal_events: TEvents,
         tx: UnboundedSender<Event>,
         cancellation_token: CancellationToken,
     ) -> Self {
-        let render_delay = Duration::from_secs_f64(1.0 / frame_rate);
+        let rates = *rates_rx.borrow();
 
         return Self {
             tx,
             cancellation_token,
             terminal_events: Box::pin(terminal_events),
-            render_interval: time::interval(render_delay),
+            rates_rx,
+            render_interval: Self::create_interval(rates.frame_rate),
+            suspend_signal: signal(SignalKind::from_raw(SIGTSTP)).ok(),
+            tick_interval: Self::create_interval(rates.tick_rate),
         };
     }
 
-    fn terminal_event_handler(&self, event: Result<CrosstermEvent, Error>) {
-        if matches!(event, Ok(CrosstermEvent::Resize(_, _))) {
-            println!("RESIZE");
+    fn create_interval(rate: f64) -> Interval {
+        return time::interval(Duration::from_secs_f64(1.0 / rate));
+    }
+
+    /// Restarts the intervals at the latest rates.
+    fn apply_rates(&mut self) {
+        let rates = *self.rates_rx.borrow_and_update();
+        self.tick_interval = Self::create_interval(rates.tick_rate);
+        self.render_interval = Self::create_interval(rates.frame_rate);
+    }
+
+    /// Waits for the next delivery of the signal, or forever if the signal
+    /// handler could not be registered.
+    async fn recv_signal(signal: &mut Option<Signal>) -> Option<()> {
+        match signal {
+            Some(signal) => signal.recv().await,
+            None => future::pending().await,
         }
+    }
 
+    fn terminal_event_handler(&self, event: Result<CrosstermEvent, Error>) {
         let event = match event {
             Ok(CrosstermEvent::Key(key)) if key.kind == KeyEventKind::Release => None,
             Ok(event) => Some(Event::Crossterm(event)),
@@ -60,6 +100,13 @@ impl<TEvents: Stream<Item = Result<CrosstermEvent, Error>> + Send + 'static> Eve
                     let _= self.tx.send(Event::Quit);
                     break;
                 },
+                Ok(_) = self.rates_rx.changed() => self.apply_rates(),
+                Some(_) = Self::recv_signal(&mut self.suspend_signal) => {
+                    let _ = self.tx.send(Event::Suspend);
+                },
+                _ = self.tick_interval.tick() => {
+                    let _ = self.tx.send(Event::Tick);
+                },
                 _ = self.render_interval.tick() => {
                     let _ = self.tx.send(Event::Render);
                 },
@@ -73,34 +120,67 @@ impl<TEvents: Stream<Item = Result<CrosstermEvent, Error>> + Send + 'static> Eve
 
 pub struct EventLoopHandler {
     rx: UnboundedReceiver<Event>,
-    #[allow(dead_code)]
     cancellation_token: CancellationToken,
+    exit_signal: Arc<OnceLock<i32>>,
+    rates_tx: watch::Sender<Rates>,
 }
 
 impl EventLoopHandler {
     pub fn new<TEvents: Stream<Item = Result<CrosstermEvent, Error>> + Send + 'static>(
         terminal_events: TEvents,
+        tick_rate: f64,
         frame_rate: f64,
     ) -> Self {
         let cancellation_token = CancellationToken::new();
         let (tx, rx) = mpsc::unbounded_channel();
+        let (rates_tx, rates_rx) = watch::channel(Rates {
+            tick_rate,
+            frame_rate,
+        });
 
-        let event_loop =
-            EventLoop::new(frame_rate, terminal_events, tx, cancellation_token.clone());
+        let event_loop = EventLoop::new(rates_rx, terminal_events, tx, cancellation_token.clone());
         tokio::spawn(event_loop.run());
 
+        let exit_signal = Arc::new(OnceLock::new());
+        tokio::spawn(watch_exit_signals(
+            cancellation_token.clone(),
+            exit_signal.clone(),
+        ));
+
         return Self {
             rx,
             cancellation_token,
+            exit_signal,
+            rates_tx,
         };
     }
 
+    /// Changes how many ticks and frames happen per second, while running.
+    pub fn set_rates(&self, tick_rate: f64, frame_rate: f64) {
+        self.rates_tx.send_if_modified(|rates| {
+            let new_rates = Rates {
+                tick_rate,
+                frame_rate,
+            };
+            let is_modified = *rates != new_rates;
+            *rates = new_rates;
+            return is_modified;
+        });
+    }
+
     pub fn cancel(&self) {
         self.cancellation_token.cancel();
     }
 
-    pub fn next(&mut self) -> Result<Event, TryRecvError> {
-        self.rx.try_recv()
+    /// The signal that shut the event loop down, if any.
+    pub fn exit_signal(&self) -> Option<i32> {
+        return self.exit_signal.get().copied();
+    }
+
+    /// Waits for the next event. Returns `None` once the event loop has
+    /// stopped and every pending event has been received.
+    pub async fn recv(&mut self) -> Option<Event> {
+        self.rx.recv().await
     }
 }
 
@@ -108,7 +188,7 @@ impl EventLoopHandler {
 mod event_loop_tests {
     use super::{EventLoopHandler, ERROR_MESSAGE};
 
-    use std::io::{Error, ErrorKind};
+    use std::io::Error;
 
     use crossterm::event::{
         Event as CrosstermEvent, KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers,
@@ -123,10 +203,22 @@ mod event_loop_tests {
     use super::Event;
 
     fn setup(events: Vec<Result<CrosstermEvent, Error>>) -> EventLoopHandler {
+        const TICK_RATE: f64 = 1.0;
         const FPS: f64 = 1.0;
-        let terminal_events = stream::iter(events.into_iter());
+        let terminal_events = stream::iter(events);
+
+        return EventLoopHandler::new(terminal_events, TICK_RATE, FPS);
+    }
+
+    fn count_events(handler: &mut EventLoopHandler, expected: &Event) -> usize {
+        let mut count = 0;
+        while let Ok(event) = handler.rx.try_recv() {
+            if event == *expected {
+                count += 1;
+            }
+        }
 
-        return EventLoopHandler::new(terminal_events, FPS);
+        return count;
     }
 
     #[tokio::test(start_paused = true)]
@@ -144,8 +236,8 @@ mod event_loop_tests {
 
         let mut response: Option<Event> = None;
         loop {
-            match handler.next() {
-                Ok(Event::Render) => {}
+            match handler.rx.try_recv() {
+                Ok(Event::Render | Event::Tick) => {}
                 Ok(event) => {
                     response = Some(event);
                     break;
@@ -177,8 +269,8 @@ mod event_loop_tests {
 
         let mut response: Option<Event> = None;
         loop {
-            match handler.next() {
-                Ok(Event::Render) => {}
+            match handler.rx.try_recv() {
+                Ok(Event::Render | Event::Tick) => {}
                 Ok(event) => {
                     response = Some(event);
                     break;
@@ -193,15 +285,15 @@ mod event_loop_tests {
 
     #[tokio::test(start_paused = true)]
     async fn test_event_loop_terminal_events_error() {
-        let error = Error::new(ErrorKind::Other, String::from("error"));
+        let error = Error::other(String::from("error"));
         let mut handler = setup(vec![Err(error)]);
 
         sleep(Duration::from_secs(1)).await;
 
         let mut response: Option<Event> = None;
         loop {
-            match handler.next() {
-                Ok(Event::Render) => {}
+            match handler.rx.try_recv() {
+                Ok(Event::Render | Event::Tick) => {}
                 Ok(event) => {
                     response = Some(event);
                     break;
@@ -224,22 +316,77 @@ mod event_loop_tests {
 
         sleep(Duration::from_secs(1)).await;
 
-        let mut received_render_event = false;
-        loop {
-            match handler.next() {
-                Ok(Event::Render) => {
-                    received_render_event = true;
-                    break;
-                }
-                Ok(_) => break,
-                Err(_) => break,
+        let render_events = count_events(&mut handler, &Event::Render);
+        handler.cancel();
+
+        assert!(render_events > 0, "Failed to receive Render Event.");
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_event_loop_tick_event() {
+        let mut handler = setup(vec![]);
+
+        sleep(Duration::from_secs(1)).await;
+
+        let tick_events = count_events(&mut handler, &Event::Tick);
+        handler.cancel();
+
+        assert!(tick_events > 0, "Failed to receive Tick Event.");
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_event_loop_tick_and_render_intervals() {
+        const TICK_RATE: f64 = 2.0;
+        const FPS: f64 = 4.0;
+        let mut handler = EventLoopHandler::new(stream::empty(), TICK_RATE, FPS);
+
+        // Both intervals fire immediately, then every 500ms (ticks) and every
+        // 250ms (renders).
+        sleep(Duration::from_millis(1100)).await;
+
+        let mut tick_events = 0;
+        let mut render_events = 0;
+        while let Ok(event) = handler.rx.try_recv() {
+            match event {
+                Event::Tick => tick_events += 1,
+                Event::Render => render_events += 1,
+                _ => {}
             }
         }
         handler.cancel();
 
-        assert_eq!(
-            received_render_event, true,
-            "Failed to receive Render Event."
+        assert_eq!(tick_events, 3, "Unexpected number of Tick Events.");
+        assert_eq!(render_events, 5, "Unexpected number of Render Events.");
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_event_loop_set_rates() {
+        const TICK_RATE: f64 = 1.0;
+        const FPS: f64 = 1.0;
+        let mut handler = EventLoopHandler::new(stream::empty(), TICK_RATE, FPS);
+
+        handler.set_rates(TICK_RATE, 4.0);
+        sleep(Duration::from_millis(1100)).await;
+
+        let render_events = count_events(&mut handler, &Event::Render);
+        handler.cancel();
+
+        assert!(
+            render_events >= 5,
+            "Frames should be rendered at the new rate, got {render_events}."
+        );
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_event_loop_recv_waits_for_event() {
+        let mut handler = setup(vec![]);
+
+        let response = handler.recv().await;
+        handler.cancel();
+
+        assert!(
+            matches!(response, Some(Event::Tick | Event::Render)),
+            "Received unexpected event: {response:?}"
         );
     }
 
@@ -252,7 +399,7 @@ mod event_loop_tests {
 
         let mut received_quit_event = false;
         loop {
-            match handler.next() {
+            match handler.rx.try_recv() {
                 Ok(Event::Quit) => {
                     received_quit_event = true;
                     break;
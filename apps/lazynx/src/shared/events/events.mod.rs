@@ -3,3 +3,6 @@ pub use event::Event;
 
 mod event_loop;
 pub use event_loop::EventLoopHandler;
+
+mod watch_exit_signals;
+pub use watch_exit_signals::wait_for_exit_signal;
@@ -0,0 +1,44 @@
+use std::sync::{Arc, OnceLock};
+
+use signal_hook::consts::{SIGHUP, SIGINT, SIGTERM};
+use tokio::signal::unix::{signal, SignalKind};
+use tokio_util::sync::CancellationToken;
+
+/// Waits for `SIGHUP`, `SIGINT` or `SIGTERM`, records which one was received
+/// and cancels the token, so the event loop shuts down through its usual
+/// `Event::Quit` path.
+pub async fn watch_exit_signals(
+    cancellation_token: CancellationToken,
+    exit_signal: Arc<OnceLock<i32>>,
+) {
+    let received = tokio::select! {
+        _ = cancellation_token.cancelled() => return,
+        received = wait_for_exit_signal() => received,
+    };
+    let Some(received) = received else {
+        return;
+    };
+
+    tracing::info!("Received exit signal {received}");
+    let _ = exit_signal.set(received);
+    cancellation_token.cancel();
+}
+
+/// Waits for `SIGHUP`, `SIGINT` or `SIGTERM` and returns which one was
+/// received, or `None` right away if they can't be listened for.
+pub async fn wait_for_exit_signal() -> Option<i32> {
+    let (Ok(mut hangup), Ok(mut interrupt), Ok(mut terminate)) = (
+        signal(SignalKind::hangup()),
+        signal(SignalKind::interrupt()),
+        signal(SignalKind::terminate()),
+    ) else {
+        tracing::warn!("Unable to listen for exit signals");
+        return None;
+    };
+
+    return Some(tokio::select! {
+        _ = hangup.recv() => SIGHUP,
+        _ = interrupt.recv() => SIGINT,
+        _ = terminate.recv() => SIGTERM,
+    });
+}
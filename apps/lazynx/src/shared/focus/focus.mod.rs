@@ -0,0 +1,5 @@
+mod focus_manager;
+pub use focus_manager::FocusManager;
+
+mod pane;
+pub use pane::Pane;
@@ -0,0 +1,90 @@
+use super::Pane;
+
+/// Keeps track of which pane has focus and the order Tab/Shift-Tab cycle
+/// through them in.
+pub struct FocusManager {
+    focused: usize,
+    order: Vec<Pane>,
+}
+
+impl Default for FocusManager {
+    fn default() -> Self {
+        return FocusManager::new(
+            vec![Pane::Workspace, Pane::Projects, Pane::Targets, Pane::Main],
+            Pane::Projects,
+        );
+    }
+}
+
+impl FocusManager {
+    pub fn new(order: Vec<Pane>, focused: Pane) -> Self {
+        let focused = order.iter().position(|pane| *pane == focused).unwrap_or(0);
+        return FocusManager { focused, order };
+    }
+
+    pub fn focused(&self) -> Pane {
+        return self.order[self.focused];
+    }
+
+    /// Focuses the pane, returning `false` if it is not managed.
+    pub fn focus(&mut self, pane: Pane) -> bool {
+        let Some(index) = self.order.iter().position(|managed| *managed == pane) else {
+            return false;
+        };
+
+        self.focused = index;
+        return true;
+    }
+
+    /// The pane Tab would move the focus to.
+    pub fn next(&self) -> Pane {
+        return self.order[(self.focused + 1) % self.order.len()];
+    }
+
+    /// The pane Shift-Tab would move the focus to.
+    pub fn prev(&self) -> Pane {
+        return self.order[(self.focused + self.order.len() - 1) % self.order.len()];
+    }
+}
+
+#[cfg(test)]
+mod focus_manager_tests {
+    use super::FocusManager;
+
+    use pretty_assertions::assert_eq;
+
+    use crate::shared::focus::Pane;
+
+    fn setup() -> FocusManager {
+        return FocusManager::new(
+            vec![Pane::Workspace, Pane::Projects, Pane::Main],
+            Pane::Workspace,
+        );
+    }
+
+    #[test]
+    fn test_next_wraps_around() {
+        let mut focus = setup();
+
+        assert_eq!(focus.next(), Pane::Projects);
+        focus.focus(Pane::Main);
+        assert_eq!(focus.next(), Pane::Workspace);
+    }
+
+    #[test]
+    fn test_prev_wraps_around() {
+        let mut focus = setup();
+
+        assert_eq!(focus.prev(), Pane::Main);
+        focus.focus(Pane::Projects);
+        assert_eq!(focus.prev(), Pane::Workspace);
+    }
+
+    #[test]
+    fn test_focus_unmanaged_pane() {
+        let mut focus = FocusManager::new(vec![Pane::Main], Pane::Main);
+
+        assert!(!focus.focus(Pane::Projects), "Pane should not be managed.");
+        assert_eq!(focus.focused(), Pane::Main);
+    }
+}
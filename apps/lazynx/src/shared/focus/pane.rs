@@ -0,0 +1,45 @@
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumIter};
+
+/// A focusable pane of the UI.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, Display, EnumIter, Serialize, Deserialize, ValueEnum,
+)]
+pub enum Pane {
+    /// Where the output of tasks is shown.
+    #[value(alias = "output")]
+    Main,
+    Workspace,
+    Projects,
+    /// Lists the targets of the selected project.
+    Targets,
+}
+
+impl Pane {
+    /// The number shown in the pane's title, which also jumps to the pane.
+    pub fn number(self) -> u8 {
+        return match self {
+            Pane::Main => 0,
+            Pane::Workspace => 1,
+            Pane::Projects => 2,
+            Pane::Targets => 3,
+        };
+    }
+}
+
+#[cfg(test)]
+mod pane_tests {
+    use super::Pane;
+
+    use std::collections::HashSet;
+
+    use strum::IntoEnumIterator;
+
+    #[test]
+    fn test_numbers_are_unique() {
+        let numbers: HashSet<u8> = Pane::iter().map(Pane::number).collect();
+
+        assert!(numbers.len() == Pane::iter().count(), "Pane numbers clash.");
+    }
+}
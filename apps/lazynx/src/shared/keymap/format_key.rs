@@ -0,0 +1,47 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+/// Formats a key press the way it is shown to users, e.g. `Ctrl+c` or
+/// `Shift+Tab`.
+pub fn format_key(key: &KeyEvent) -> String {
+    let code = match key.code {
+        KeyCode::BackTab => return String::from("Shift+Tab"),
+        KeyCode::Char(' ') => String::from("Space"),
+        KeyCode::Char(char) => char.to_string(),
+        KeyCode::Esc => String::from("Esc"),
+        KeyCode::F(number) => format!("F{number}"),
+        code => code.to_string(),
+    };
+
+    let mut modifiers = Vec::new();
+    if key.modifiers.contains(KeyModifiers::CONTROL) {
+        modifiers.push("Ctrl");
+    }
+    if key.modifiers.contains(KeyModifiers::ALT) {
+        modifiers.push("Alt");
+    }
+    if key.modifiers.contains(KeyModifiers::SHIFT) && !matches!(key.code, KeyCode::Char(_)) {
+        modifiers.push("Shift");
+    }
+
+    modifiers.push(&code);
+
+    return modifiers.join("+");
+}
+
+#[cfg(test)]
+mod format_key_tests {
+    use super::format_key;
+
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use pretty_assertions::assert_eq;
+    use test_case::test_case;
+
+    #[test_case(KeyCode::Char('c'), KeyModifiers::CONTROL, "Ctrl+c"; "Control")]
+    #[test_case(KeyCode::Char('?'), KeyModifiers::SHIFT, "?"; "Shifted Character")]
+    #[test_case(KeyCode::BackTab, KeyModifiers::SHIFT, "Shift+Tab"; "Back Tab")]
+    #[test_case(KeyCode::Down, KeyModifiers::empty(), "Down"; "Arrow")]
+    #[test_case(KeyCode::Enter, KeyModifiers::ALT, "Alt+Enter"; "Alt")]
+    fn test_format_key(code: KeyCode, modifiers: KeyModifiers, expected: &str) {
+        assert_eq!(format_key(&KeyEvent::new(code, modifiers)), expected);
+    }
+}
@@ -0,0 +1,91 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use serde::{Deserialize, Serialize};
+
+use crate::shared::actions::Action;
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct KeyBinding {
+    pub action: Action,
+    pub key: KeyEvent,
+}
+
+impl KeyBinding {
+    pub fn new(code: KeyCode, modifiers: KeyModifiers, action: Action) -> Self {
+        return KeyBinding {
+            action,
+            key: KeyEvent::new(code, modifiers),
+        };
+    }
+
+    /// Whether the key press triggers this binding.
+    pub fn matches(&self, key: &KeyEvent) -> bool {
+        return KeyBinding::is_same_key(&self.key, key);
+    }
+
+    /// Whether both key presses are the same key. Shift is ignored for keys
+    /// where it is already part of the key itself, like `?` or Shift-Tab.
+    pub fn is_same_key(key: &KeyEvent, other: &KeyEvent) -> bool {
+        return key.code == other.code
+            && without_implied_shift(key) == without_implied_shift(other);
+    }
+
+    /// A short, human readable explanation of what the binding does.
+    pub fn description(&self) -> String {
+        return match &self.action {
+            Action::CommandPalette => String::from("Open the command palette"),
+            Action::Doctor => String::from("Check the environment"),
+            Action::FilterProjects => String::from("Filter the projects"),
+            Action::Focus(pane) => format!("Focus the {pane} pane"),
+            Action::FocusNext => String::from("Focus the next pane"),
+            Action::FocusPrev => String::from("Focus the previous pane"),
+            Action::GrowSidebar => String::from("Widen the sidebar"),
+            Action::Help => String::from("Show this help"),
+            Action::Quit => String::from("Quit"),
+            Action::RunCommand(command) => format!("Run `{command}`"),
+            Action::RunSelectedTarget => String::from("Run the selected target"),
+            Action::RunTarget {
+                project,
+                target,
+                configuration: Some(configuration),
+            } => format!("Run {project}:{target}:{configuration}"),
+            Action::RunTarget {
+                project, target, ..
+            } => format!("Run {project}:{target}"),
+            Action::ScrollDown => String::from("Scroll down"),
+            Action::ScrollUp => String::from("Scroll up"),
+            Action::SelectNext => String::from("Select the next item"),
+            Action::SelectPrev => String::from("Select the previous item"),
+            Action::SelectProject(name) => format!("Select the {name} project"),
+            Action::ShrinkSidebar => String::from("Narrow the sidebar"),
+            Action::Suspend => String::from("Suspend to the shell"),
+            action => action.to_string(),
+        };
+    }
+}
+
+fn without_implied_shift(key: &KeyEvent) -> KeyModifiers {
+    return match key.code {
+        KeyCode::Char(_) | KeyCode::BackTab => key.modifiers.difference(KeyModifiers::SHIFT),
+        _ => key.modifiers,
+    };
+}
+
+#[cfg(test)]
+mod key_binding_tests {
+    use super::KeyBinding;
+
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use test_case::test_case;
+
+    use crate::shared::actions::Action;
+
+    #[test_case(KeyCode::Char('?'), KeyModifiers::empty(), true; "Exact Match")]
+    #[test_case(KeyCode::Char('?'), KeyModifiers::SHIFT, true; "Shift Is Implied")]
+    #[test_case(KeyCode::Char('?'), KeyModifiers::CONTROL, false; "Other Modifiers")]
+    #[test_case(KeyCode::Char('/'), KeyModifiers::empty(), false; "Other Key")]
+    fn test_matches(code: KeyCode, modifiers: KeyModifiers, expected: bool) {
+        let binding = KeyBinding::new(KeyCode::Char('?'), KeyModifiers::empty(), Action::Help);
+
+        assert_eq!(binding.matches(&KeyEvent::new(code, modifiers)), expected);
+    }
+}
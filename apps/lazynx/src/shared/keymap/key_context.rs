@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumIter};
+
+use crate::shared::focus::Pane;
+
+/// Where a key binding applies. `Global` bindings work regardless of which
+/// pane is focused; the others only while their pane is.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    Display,
+    EnumIter,
+    Serialize,
+    Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum KeyContext {
+    Global,
+    Main,
+    Workspace,
+    Projects,
+    Targets,
+}
+
+impl From<Pane> for KeyContext {
+    fn from(pane: Pane) -> Self {
+        return match pane {
+            Pane::Main => KeyContext::Main,
+            Pane::Workspace => KeyContext::Workspace,
+            Pane::Projects => KeyContext::Projects,
+            Pane::Targets => KeyContext::Targets,
+        };
+    }
+}
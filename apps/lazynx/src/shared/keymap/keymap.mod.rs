@@ -0,0 +1,17 @@
+mod key_binding;
+pub use key_binding::KeyBinding;
+
+mod key_context;
+pub use key_context::KeyContext;
+
+mod format_key;
+pub use format_key::format_key;
+
+mod keymap;
+pub use keymap::Keymap;
+
+mod parse_action;
+pub use parse_action::parse_action;
+
+mod parse_key;
+pub use parse_key::parse_key;
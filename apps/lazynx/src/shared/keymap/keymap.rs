@@ -0,0 +1,262 @@
+use std::collections::BTreeMap;
+
+use color_eyre::eyre::{bail, eyre, Result};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use strum::IntoEnumIterator;
+
+use super::{parse_action, parse_key, KeyBinding, KeyContext};
+use crate::shared::{actions::Action, focus::Pane};
+
+/// The action that removes the binding of a key, e.g. `"<Ctrl-d>" = "None"`.
+const UNBIND: &str = "None";
+
+/// Every key binding in the app, grouped by the context they apply in.
+///
+/// Components look up key presses here instead of matching on keys
+/// themselves, so the help popup can be generated from the same bindings.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Keymap {
+    bindings: BTreeMap<KeyContext, Vec<KeyBinding>>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        let none = KeyModifiers::empty();
+        let ctrl = KeyModifiers::CONTROL;
+
+        let focus_pane = |pane: Pane| {
+            let number = char::from_digit(pane.number().into(), 10).unwrap_or_default();
+            return KeyBinding::new(KeyCode::Char(number), none, Action::Focus(pane));
+        };
+
+        let mut global = vec![
+            KeyBinding::new(KeyCode::Char('c'), ctrl, Action::Quit),
+            KeyBinding::new(KeyCode::Char('d'), ctrl, Action::Quit),
+            KeyBinding::new(KeyCode::Char('z'), ctrl, Action::Suspend),
+            KeyBinding::new(KeyCode::Char('?'), none, Action::Help),
+            KeyBinding::new(KeyCode::Char(':'), none, Action::CommandPalette),
+            KeyBinding::new(KeyCode::Char('p'), ctrl, Action::CommandPalette),
+            KeyBinding::new(KeyCode::Tab, none, Action::FocusNext),
+            KeyBinding::new(KeyCode::BackTab, KeyModifiers::SHIFT, Action::FocusPrev),
+            KeyBinding::new(KeyCode::Char('<'), none, Action::ShrinkSidebar),
+            KeyBinding::new(KeyCode::Char('>'), none, Action::GrowSidebar),
+        ];
+        global.extend(Pane::iter().map(focus_pane));
+
+        let projects = vec![
+            KeyBinding::new(KeyCode::Char('j'), none, Action::SelectNext),
+            KeyBinding::new(KeyCode::Down, none, Action::SelectNext),
+            KeyBinding::new(KeyCode::Char('k'), none, Action::SelectPrev),
+            KeyBinding::new(KeyCode::Up, none, Action::SelectPrev),
+            KeyBinding::new(KeyCode::Char('/'), none, Action::FilterProjects),
+        ];
+
+        let targets = vec![
+            KeyBinding::new(KeyCode::Char('j'), none, Action::SelectNext),
+            KeyBinding::new(KeyCode::Down, none, Action::SelectNext),
+            KeyBinding::new(KeyCode::Char('k'), none, Action::SelectPrev),
+            KeyBinding::new(KeyCode::Up, none, Action::SelectPrev),
+            KeyBinding::new(KeyCode::Enter, none, Action::RunSelectedTarget),
+        ];
+
+        let main = vec![
+            KeyBinding::new(KeyCode::Char('j'), none, Action::ScrollDown),
+            KeyBinding::new(KeyCode::Down, none, Action::ScrollDown),
+            KeyBinding::new(KeyCode::Char('k'), none, Action::ScrollUp),
+            KeyBinding::new(KeyCode::Up, none, Action::ScrollUp),
+        ];
+
+        return Keymap {
+            bindings: BTreeMap::from([
+                (KeyContext::Global, global),
+                (KeyContext::Main, main),
+                (KeyContext::Projects, projects),
+                (KeyContext::Targets, targets),
+            ]),
+        };
+    }
+}
+
+impl Keymap {
+    /// Applies the `[keybindings.<context>]` sections of the config, each
+    /// binding replacing any other on the same key within its context. Keys
+    /// bound to `None` are left unbound in the context.
+    ///
+    /// Fails on keys or actions that don't parse and on a key bound twice
+    /// within a context. Pane bindings may shadow global ones, since they are
+    /// looked up first while their pane is focused.
+    pub fn with_keybindings(
+        mut self,
+        keybindings: &BTreeMap<KeyContext, BTreeMap<String, String>>,
+    ) -> Result<Self> {
+        for (context, section) in keybindings {
+            let section_name = format!("[keybindings.{}]", context.to_string().to_lowercase());
+            let mut configured: Vec<(&String, KeyEvent, Option<Action>)> = Vec::new();
+
+            for (text, action) in section {
+                let in_section = |error| eyre!("{section_name} `{text}`: {error}");
+                let key = parse_key(text).map_err(in_section)?;
+                let action = match action.trim() {
+                    UNBIND => None,
+                    action => Some(parse_action(action).map_err(in_section)?),
+                };
+
+                let same_key = configured
+                    .iter()
+                    .find(|(_, other, _)| KeyBinding::is_same_key(other, &key));
+                if let Some((other, ..)) = same_key {
+                    bail!("`{other}` and `{text}` bind the same key in {section_name}.");
+                }
+                configured.push((text, key, action));
+            }
+
+            let bindings = self.bindings.entry(*context).or_default();
+            bindings.retain(|binding| !configured.iter().any(|(_, key, _)| binding.matches(key)));
+            bindings.extend(configured.into_iter().filter_map(|(_, key, action)| {
+                Some(KeyBinding {
+                    action: action?,
+                    key,
+                })
+            }));
+        }
+
+        return Ok(self);
+    }
+
+    /// The action bound to the key press within the context, if any.
+    pub fn action_for(&self, context: KeyContext, key: &KeyEvent) -> Option<Action> {
+        return self
+            .find_binding(context, key)
+            .map(|binding| binding.action.clone());
+    }
+
+    fn find_binding(&self, context: KeyContext, key: &KeyEvent) -> Option<&KeyBinding> {
+        return self
+            .bindings_for(context)
+            .iter()
+            .find(|binding| binding.matches(key));
+    }
+
+    pub fn bindings_for(&self, context: KeyContext) -> &[KeyBinding] {
+        return self
+            .bindings
+            .get(&context)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+    }
+}
+
+#[cfg(test)]
+mod keymap_tests {
+    use super::Keymap;
+
+    use std::collections::BTreeMap;
+
+    use color_eyre::eyre::Result;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use pretty_assertions::assert_eq;
+    use test_case::test_case;
+
+    use crate::shared::{actions::Action, focus::Pane, keymap::KeyContext};
+
+    #[test_case(KeyContext::Global, KeyCode::Char('?'), Some(Action::Help); "Help")]
+    #[test_case(KeyContext::Global, KeyCode::Char('2'), Some(Action::Focus(Pane::Projects)); "Focus Pane")]
+    #[test_case(KeyContext::Projects, KeyCode::Down, Some(Action::SelectNext); "Pane Binding")]
+    #[test_case(KeyContext::Main, KeyCode::Down, Some(Action::ScrollDown); "Main Pane Binding")]
+    #[test_case(KeyContext::Workspace, KeyCode::Down, None; "Unbound Context")]
+    #[test_case(KeyContext::Global, KeyCode::Char('j'), None; "Binding From Other Context")]
+    fn test_action_for(context: KeyContext, code: KeyCode, expected: Option<Action>) {
+        let keymap = Keymap::default();
+
+        let action = keymap.action_for(context, &KeyEvent::new(code, KeyModifiers::empty()));
+
+        assert_eq!(action, expected);
+    }
+
+    fn keybindings(
+        context: KeyContext,
+        bindings: &[(&str, &str)],
+    ) -> BTreeMap<KeyContext, BTreeMap<String, String>> {
+        let bindings = bindings
+            .iter()
+            .map(|(key, action)| (key.to_string(), action.to_string()))
+            .collect();
+
+        return BTreeMap::from([(context, bindings)]);
+    }
+
+    #[test]
+    fn test_keybindings_replace_defaults() -> Result<()> {
+        let keymap = Keymap::default().with_keybindings(&keybindings(
+            KeyContext::Global,
+            &[("<Ctrl-c>", "Help"), ("q", "Quit")],
+        ))?;
+        let ctrl_c = KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL);
+        let q = KeyEvent::new(KeyCode::Char('q'), KeyModifiers::empty());
+
+        assert_eq!(
+            keymap.action_for(KeyContext::Global, &ctrl_c),
+            Some(Action::Help)
+        );
+        assert_eq!(
+            keymap.action_for(KeyContext::Global, &q),
+            Some(Action::Quit)
+        );
+        assert_eq!(
+            keymap
+                .bindings_for(KeyContext::Global)
+                .iter()
+                .filter(|binding| binding.key == ctrl_c)
+                .count(),
+            1,
+            "The default binding should have been replaced."
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pane_bindings_may_shadow_global_ones() -> Result<()> {
+        let keymap = Keymap::default()
+            .with_keybindings(&keybindings(KeyContext::Main, &[("?", "ScrollDown")]))?;
+        let question_mark = KeyEvent::new(KeyCode::Char('?'), KeyModifiers::empty());
+
+        assert_eq!(
+            keymap.action_for(KeyContext::Main, &question_mark),
+            Some(Action::ScrollDown)
+        );
+        assert_eq!(
+            keymap.action_for(KeyContext::Global, &question_mark),
+            Some(Action::Help)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_none_unbinds_a_key() -> Result<()> {
+        let keymap = Keymap::default()
+            .with_keybindings(&keybindings(KeyContext::Global, &[("<Ctrl-d>", "None")]))?;
+        let ctrl_d = KeyEvent::new(KeyCode::Char('d'), KeyModifiers::CONTROL);
+        let ctrl_c = KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL);
+
+        assert_eq!(keymap.action_for(KeyContext::Global, &ctrl_d), None);
+        assert_eq!(
+            keymap.action_for(KeyContext::Global, &ctrl_c),
+            Some(Action::Quit)
+        );
+
+        Ok(())
+    }
+
+    #[test_case(KeyContext::Global, &[("<Ctrl-c>", "Help"), ("<C-c>", "Quit")], "`<C-c>` and `<Ctrl-c>` bind the same key in [keybindings.global]."; "Same Key Twice")]
+    #[test_case(KeyContext::Main, &[("<Ctrl-x>", "ScrollDown"), ("<C-x>", "None")], "`<C-x>` and `<Ctrl-x>` bind the same key in [keybindings.main]."; "Unbound And Bound")]
+    #[test_case(KeyContext::Projects, &[("<Hyper-x>", "Quit")], "[keybindings.projects] `<Hyper-x>`: Unknown modifier `Hyper`. Valid modifiers are: Ctrl, Alt, Shift."; "Invalid Key")]
+    fn test_keybinding_conflicts(context: KeyContext, bindings: &[(&str, &str)], expected: &str) {
+        let error = Keymap::default()
+            .with_keybindings(&keybindings(context, bindings))
+            .unwrap_err();
+
+        assert_eq!(error.to_string(), expected);
+    }
+}
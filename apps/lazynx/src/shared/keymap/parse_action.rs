@@ -0,0 +1,95 @@
+use color_eyre::eyre::{bail, eyre, Result};
+use serde_json::json;
+
+use crate::shared::actions::Action;
+
+/// The actions that can be bound to keys. The others are sent by the app
+/// itself, e.g. `Render` or `ProcessOutput`.
+const BINDABLE_ACTIONS: [&str; 18] = [
+    "CommandPalette",
+    "Doctor",
+    "FilterProjects",
+    "Focus",
+    "FocusNext",
+    "FocusPrev",
+    "GrowSidebar",
+    "Help",
+    "Quit",
+    "RunCommand",
+    "RunSelectedTarget",
+    "ScrollDown",
+    "ScrollUp",
+    "SelectNext",
+    "SelectPrev",
+    "SelectProject",
+    "ShrinkSidebar",
+    "Suspend",
+];
+
+/// Parses an action as written in the config: its name, followed by its
+/// argument for the actions that take one, e.g. `Quit`, `Focus Projects` or
+/// `RunCommand npx nx graph`. Only the actions users bind keys to are
+/// accepted.
+pub fn parse_action(text: &str) -> Result<Action> {
+    let text = text.trim();
+    let (name, value) = match text.split_once(' ') {
+        Some((name, argument)) => (name, json!({ name: argument.trim() })),
+        None => (text, json!(text)),
+    };
+
+    if !BINDABLE_ACTIONS.contains(&name) {
+        bail!(
+            "Invalid action `{text}`. Valid actions are: {}.",
+            BINDABLE_ACTIONS.join(", ")
+        );
+    }
+
+    return serde_json::from_value(value)
+        .map_err(|error| eyre!("Invalid action `{text}`: {error}."));
+}
+
+#[cfg(test)]
+mod parse_action_tests {
+    use super::parse_action;
+
+    use color_eyre::eyre::Result;
+    use pretty_assertions::assert_eq;
+    use test_case::test_case;
+
+    use crate::shared::{actions::Action, focus::Pane};
+
+    #[test_case("Quit", Action::Quit; "Without Argument")]
+    #[test_case("Focus Projects", Action::Focus(Pane::Projects); "Pane Argument")]
+    #[test_case("RunCommand npx nx graph", Action::RunCommand(String::from("npx nx graph")); "Argument With Spaces")]
+    fn test_parse_action(text: &str, expected: Action) -> Result<()> {
+        assert_eq!(parse_action(text)?, expected);
+
+        Ok(())
+    }
+
+    #[test_case("Quitt"; "Unknown Action")]
+    #[test_case("Focus Sidebar"; "Unknown Argument")]
+    #[test_case("Quit now"; "Unexpected Argument")]
+    #[test_case("Render"; "Internal Action")]
+    #[test_case("OpenOverlay Help"; "Internal Action With Argument")]
+    fn test_parse_invalid_action(text: &str) {
+        assert!(parse_action(text).is_err(), "`{text}` should not parse.");
+    }
+
+    #[test_case("Init"; "Init")]
+    #[test_case("ProcessOutput"; "Process Output")]
+    #[test_case("Confirmed Quit"; "Confirmed")]
+    fn test_internal_actions_list_valid_ones(text: &str) {
+        let error = parse_action(text).unwrap_err().to_string();
+
+        assert_eq!(
+            error,
+            format!(
+                "Invalid action `{text}`. Valid actions are: CommandPalette, Doctor, \
+                 FilterProjects, Focus, FocusNext, FocusPrev, GrowSidebar, Help, Quit, \
+                 RunCommand, RunSelectedTarget, ScrollDown, ScrollUp, SelectNext, SelectPrev, \
+                 SelectProject, ShrinkSidebar, Suspend."
+            )
+        );
+    }
+}
@@ -0,0 +1,115 @@
+use color_eyre::eyre::{bail, Result};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+/// Parses a key as written in the config, e.g. `q`, `?`, `<Ctrl-c>`,
+/// `<S-Tab>` or `<F1>`. Modifiers and key names are case-insensitive, since
+/// the config lowercases its keys, so uppercase letters are written with
+/// Shift, e.g. `<Shift-g>`.
+pub fn parse_key(text: &str) -> Result<KeyEvent> {
+    let mut chars = text.chars();
+    if let (Some(char), None) = (chars.next(), chars.next()) {
+        return Ok(KeyEvent::new(KeyCode::Char(char), KeyModifiers::empty()));
+    }
+
+    let Some(inner) = text
+        .strip_prefix('<')
+        .and_then(|text| text.strip_suffix('>'))
+    else {
+        bail!("Expected a single character or a key in angle brackets, like `<Ctrl-c>`.");
+    };
+
+    // Split off the modifiers from the front, so `<Ctrl-->` binds the minus.
+    let mut modifiers = KeyModifiers::empty();
+    let mut name = inner;
+    while let Some((modifier, rest)) = name.split_once('-') {
+        if rest.is_empty() {
+            break;
+        }
+        modifiers |= match modifier.to_lowercase().as_str() {
+            "c" | "ctrl" => KeyModifiers::CONTROL,
+            "a" | "m" | "alt" => KeyModifiers::ALT,
+            "s" | "shift" => KeyModifiers::SHIFT,
+            _ => bail!("Unknown modifier `{modifier}`. Valid modifiers are: Ctrl, Alt, Shift."),
+        };
+        name = rest;
+    }
+
+    let code = match name.to_lowercase().as_str() {
+        "backspace" | "bs" => KeyCode::Backspace,
+        "backtab" => KeyCode::BackTab,
+        "del" | "delete" => KeyCode::Delete,
+        "down" => KeyCode::Down,
+        "end" => KeyCode::End,
+        "enter" | "cr" | "return" => KeyCode::Enter,
+        "esc" => KeyCode::Esc,
+        "home" => KeyCode::Home,
+        "insert" => KeyCode::Insert,
+        "left" => KeyCode::Left,
+        "lt" => KeyCode::Char('<'),
+        "gt" => KeyCode::Char('>'),
+        "pagedown" => KeyCode::PageDown,
+        "pageup" => KeyCode::PageUp,
+        "right" => KeyCode::Right,
+        "space" => KeyCode::Char(' '),
+        "tab" => KeyCode::Tab,
+        "up" => KeyCode::Up,
+        lower => match (lower.strip_prefix('f'), name.chars().count()) {
+            (Some(number), _) if number.parse::<u8>().is_ok_and(|n| (1..=12).contains(&n)) => {
+                KeyCode::F(number.parse()?)
+            }
+            (_, 1) => KeyCode::Char(lower.chars().next().unwrap_or_default()),
+            _ => bail!("Unknown key `{name}`."),
+        },
+    };
+
+    // Shift is part of the character itself, and Shift-Tab is its own key.
+    return Ok(match code {
+        KeyCode::Char(char) if modifiers.contains(KeyModifiers::SHIFT) => KeyEvent::new(
+            KeyCode::Char(char.to_ascii_uppercase()),
+            modifiers.difference(KeyModifiers::SHIFT),
+        ),
+        KeyCode::Tab if modifiers.contains(KeyModifiers::SHIFT) => {
+            KeyEvent::new(KeyCode::BackTab, modifiers)
+        }
+        KeyCode::BackTab => KeyEvent::new(KeyCode::BackTab, modifiers | KeyModifiers::SHIFT),
+        code => KeyEvent::new(code, modifiers),
+    });
+}
+
+#[cfg(test)]
+mod parse_key_tests {
+    use super::parse_key;
+
+    use color_eyre::eyre::Result;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use pretty_assertions::assert_eq;
+    use test_case::test_case;
+
+    #[test_case("q", KeyCode::Char('q'), KeyModifiers::empty(); "Character")]
+    #[test_case("?", KeyCode::Char('?'), KeyModifiers::empty(); "Symbol")]
+    #[test_case("<Ctrl-c>", KeyCode::Char('c'), KeyModifiers::CONTROL; "Control")]
+    #[test_case("<ctrl-c>", KeyCode::Char('c'), KeyModifiers::CONTROL; "Lowercase Control")]
+    #[test_case("<C-A-x>", KeyCode::Char('x'), KeyModifiers::CONTROL | KeyModifiers::ALT; "Short Modifiers")]
+    #[test_case("<Shift-g>", KeyCode::Char('G'), KeyModifiers::empty(); "Uppercase Letter")]
+    #[test_case("<S-Tab>", KeyCode::BackTab, KeyModifiers::SHIFT; "Shift Tab")]
+    #[test_case("<BackTab>", KeyCode::BackTab, KeyModifiers::SHIFT; "Back Tab")]
+    #[test_case("<Down>", KeyCode::Down, KeyModifiers::empty(); "Arrow")]
+    #[test_case("<space>", KeyCode::Char(' '), KeyModifiers::empty(); "Space")]
+    #[test_case("<F12>", KeyCode::F(12), KeyModifiers::empty(); "Function Key")]
+    #[test_case("<Ctrl-->", KeyCode::Char('-'), KeyModifiers::CONTROL; "Control Minus")]
+    #[test_case("<Alt-Enter>", KeyCode::Enter, KeyModifiers::ALT; "Alt Enter")]
+    fn test_parse_key(text: &str, code: KeyCode, modifiers: KeyModifiers) -> Result<()> {
+        assert_eq!(parse_key(text)?, KeyEvent::new(code, modifiers));
+
+        Ok(())
+    }
+
+    #[test_case(""; "Empty")]
+    #[test_case("qq"; "Several Characters")]
+    #[test_case("<Hyper-c>"; "Unknown Modifier")]
+    #[test_case("<Ctrl-Pause>"; "Unknown Key")]
+    #[test_case("<F13>"; "Unknown Function Key")]
+    fn test_parse_invalid_key(text: &str) {
+        assert!(parse_key(text).is_err(), "`{text}` should not parse.");
+    }
+}
@@ -1,4 +1,4 @@
 mod logger;
-pub use logger::{init, Config};
+pub use logger::{init, set_log_level, Config};
 
 pub mod trace_debug;
@@ -1,15 +1,25 @@
 use std::{
     fs::{create_dir_all, File},
     path::PathBuf,
+    sync::OnceLock,
 };
 
 use color_eyre::eyre::Result;
 use tracing::level_filters::LevelFilter;
 use tracing_error::ErrorLayer;
 use tracing_subscriber::{
-    self, filter::EnvFilter, prelude::__tracing_subscriber_SubscriberExt, util::SubscriberInitExt,
+    self,
+    filter::EnvFilter,
+    prelude::__tracing_subscriber_SubscriberExt,
+    reload::{self, Handle},
+    util::SubscriberInitExt,
+    Registry,
 };
 
+/// Swaps the filter of the running logger, so the log level can change
+/// without a restart.
+static FILTER_HANDLE: OnceLock<Handle<EnvFilter, Registry>> = OnceLock::new();
+
 /// Configuration for the logger.
 ///
 /// The logger is configured using environment variables.
@@ -22,6 +32,14 @@ pub struct Config {
     pub log_level: Option<LevelFilter>,
 }
 
+fn create_filter(log_level: Option<LevelFilter>) -> EnvFilter {
+    return EnvFilter::from_default_env()
+        .add_directive("tokio_util=off".parse().unwrap())
+        .add_directive("hyper=off".parse().unwrap())
+        .add_directive("reqwest=off".parse().unwrap())
+        .add_directive(log_level.unwrap_or(LevelFilter::OFF).into());
+}
+
 pub fn init(config: Config) -> Result<()> {
     let directory = config.data_dir.clone();
     create_dir_all(directory.clone())?;
@@ -37,17 +55,24 @@ pub fn init(config: Config) -> Result<()> {
         .with_target(false)
         .with_ansi(false);
 
-    let env_filter = EnvFilter::from_default_env()
-        .add_directive("tokio_util=off".parse().unwrap())
-        .add_directive("hyper=off".parse().unwrap())
-        .add_directive("reqwest=off".parse().unwrap())
-        .add_directive(config.log_level.unwrap_or(LevelFilter::OFF).into());
+    let (env_filter, filter_handle) = reload::Layer::new(create_filter(config.log_level));
+    let _ = FILTER_HANDLE.set(filter_handle);
 
     tracing_subscriber::registry()
+        .with(env_filter)
         .with(file_subscriber)
         .with(ErrorLayer::default())
-        .with(env_filter)
         .init();
 
     Ok(())
 }
+
+/// Changes the log level of the running logger. Does nothing if the logger
+/// was never initialized.
+pub fn set_log_level(log_level: Option<LevelFilter>) -> Result<()> {
+    if let Some(handle) = FILTER_HANDLE.get() {
+        handle.reload(create_filter(log_level))?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,269 @@
+use std::cmp::Reverse;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use fuzzy_matcher::{skim::SkimMatcherV2, FuzzyMatcher};
+use ratatui::{
+    layout::{Constraint, Layout, Position, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, List, ListItem, ListState, Paragraph},
+    Frame,
+};
+use serde::{Deserialize, Serialize};
+
+use super::{popup_area, OverlayOutcome};
+use crate::shared::{actions::Action, theme::Theme};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct PaletteEntry {
+    pub action: Action,
+    /// The keys bound to the action, shown so people learn the shortcuts.
+    pub key_hint: String,
+    pub label: String,
+}
+
+impl PaletteEntry {
+    pub fn new(label: &str, action: Action) -> Self {
+        return PaletteEntry {
+            action,
+            key_hint: String::new(),
+            label: label.to_owned(),
+        };
+    }
+
+    pub fn with_key_hint(mut self, key_hint: &str) -> Self {
+        self.key_hint = key_hint.to_owned();
+        return self;
+    }
+}
+
+/// Fuzzy-searches a list of entries and dispatches the chosen one's action.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct CommandPalette {
+    pub entries: Vec<PaletteEntry>,
+    pub query: String,
+    pub selected: usize,
+}
+
+impl CommandPalette {
+    pub fn new(entries: Vec<PaletteEntry>) -> Self {
+        return CommandPalette {
+            entries,
+            query: String::new(),
+            selected: 0,
+        };
+    }
+
+    /// The entries matching the query, best match first, along with the
+    /// positions of the matched characters in their labels.
+    pub fn matches(&self) -> Vec<(&PaletteEntry, Vec<usize>)> {
+        if self.query.is_empty() {
+            return self
+                .entries
+                .iter()
+                .map(|entry| (entry, Vec::new()))
+                .collect();
+        }
+
+        let matcher = SkimMatcherV2::default().ignore_case();
+        let mut matches: Vec<(i64, &PaletteEntry, Vec<usize>)> = self
+            .entries
+            .iter()
+            .filter_map(|entry| {
+                let (score, indices) = matcher.fuzzy_indices(&entry.label, &self.query)?;
+                return Some((score, entry, indices));
+            })
+            .collect();
+
+        matches.sort_by_key(|(score, _, _)| Reverse(*score));
+
+        return matches
+            .into_iter()
+            .map(|(_, entry, indices)| (entry, indices))
+            .collect();
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> OverlayOutcome {
+        let is_ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+
+        match key.code {
+            KeyCode::Esc => return OverlayOutcome::Close(None),
+            KeyCode::Enter => {
+                let action = self
+                    .matches()
+                    .get(self.selected)
+                    .map(|(entry, _)| entry.action.clone());
+                return OverlayOutcome::Close(action);
+            }
+            KeyCode::Down | KeyCode::Tab => self.select_next(),
+            KeyCode::Char('n') if is_ctrl => self.select_next(),
+            KeyCode::Up | KeyCode::BackTab => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Char('p') if is_ctrl => self.selected = self.selected.saturating_sub(1),
+            KeyCode::Backspace => {
+                self.query.pop();
+                self.selected = 0;
+            }
+            KeyCode::Char(char) if !is_ctrl => {
+                self.query.push(char);
+                self.selected = 0;
+            }
+            _ => {}
+        }
+
+        return OverlayOutcome::Open;
+    }
+
+    fn select_next(&mut self) {
+        let last = self.matches().len().saturating_sub(1);
+        self.selected = (self.selected + 1).min(last);
+    }
+
+    fn create_item(
+        &self,
+        entry: &PaletteEntry,
+        indices: &[usize],
+        width: usize,
+        theme: &Theme,
+    ) -> ListItem<'_> {
+        let highlight = Style::default()
+            .fg(theme.highlight)
+            .add_modifier(Modifier::BOLD);
+
+        let mut spans: Vec<Span> = vec![Span::raw(" ")];
+        spans.extend(entry.label.chars().enumerate().map(|(index, char)| {
+            match indices.contains(&index) {
+                true => Span::styled(char.to_string(), highlight),
+                false => Span::raw(char.to_string()),
+            }
+        }));
+
+        let used = entry.label.chars().count() + entry.key_hint.chars().count() + 2;
+        spans.push(Span::raw(" ".repeat(width.saturating_sub(used))));
+        spans.push(Span::styled(
+            entry.key_hint.clone(),
+            Style::default().fg(theme.muted),
+        ));
+
+        return ListItem::new(Line::from(spans));
+    }
+
+    pub fn draw(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let matches = self.matches();
+        let height = (matches.len() as u16 + 4).clamp(6, 20);
+        let area = popup_area(area, 70, height);
+
+        let block = Block::default()
+            .title("─Command Palette─")
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(theme.border_style(true));
+        let inner = block.inner(area);
+        let [input_area, list_area] =
+            Layout::vertical([Constraint::Length(2), Constraint::Fill(1)]).areas(inner);
+
+        let input = Paragraph::new(format!(" > {}", self.query)).block(
+            Block::default()
+                .borders(Borders::BOTTOM)
+                .border_style(Style::default().fg(theme.muted)),
+        );
+
+        let width = list_area.width as usize;
+        let items: Vec<ListItem> = matches
+            .iter()
+            .map(|(entry, indices)| self.create_item(entry, indices, width, theme))
+            .collect();
+        let list =
+            List::new(items).highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+        let mut state = ListState::default().with_selected(Some(self.selected));
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(block, area);
+        frame.render_widget(input, input_area);
+        frame.render_stateful_widget(list, list_area, &mut state);
+        frame.set_cursor_position(Position::new(
+            input_area.x + 3 + self.query.chars().count() as u16,
+            input_area.y,
+        ));
+    }
+}
+
+#[cfg(test)]
+mod command_palette_tests {
+    use super::{CommandPalette, PaletteEntry};
+
+    use color_eyre::eyre::Result;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use insta::assert_snapshot;
+    use pretty_assertions::assert_eq;
+    use ratatui::{backend::TestBackend, Terminal};
+
+    use crate::shared::{actions::Action, focus::Pane, overlay::OverlayOutcome, theme::Theme};
+
+    fn key(code: KeyCode) -> KeyEvent {
+        return KeyEvent::new(code, KeyModifiers::empty());
+    }
+
+    fn setup() -> CommandPalette {
+        return CommandPalette::new(vec![
+            PaletteEntry::new("Show this help", Action::Help).with_key_hint("?"),
+            PaletteEntry::new("Quit", Action::Quit).with_key_hint("Ctrl+c"),
+            PaletteEntry::new("Focus the Projects pane", Action::Focus(Pane::Projects))
+                .with_key_hint("2"),
+        ]);
+    }
+
+    fn type_query(palette: &mut CommandPalette, query: &str) {
+        for char in query.chars() {
+            palette.handle_key(key(KeyCode::Char(char)));
+        }
+    }
+
+    #[test]
+    fn test_fuzzy_search() {
+        let mut palette = setup();
+
+        type_query(&mut palette, "fcprj");
+
+        let labels: Vec<&str> = palette
+            .matches()
+            .iter()
+            .map(|(entry, _)| entry.label.as_str())
+            .collect();
+        assert_eq!(labels, vec!["Focus the Projects pane"]);
+    }
+
+    #[test]
+    fn test_run_selected_entry() {
+        let mut palette = setup();
+
+        palette.handle_key(key(KeyCode::Down));
+        let outcome = palette.handle_key(key(KeyCode::Enter));
+
+        assert_eq!(outcome, OverlayOutcome::Close(Some(Action::Quit)));
+    }
+
+    #[test]
+    fn test_no_match_does_nothing() {
+        let mut palette = setup();
+
+        type_query(&mut palette, "zzz");
+
+        assert_eq!(
+            palette.handle_key(key(KeyCode::Enter)),
+            OverlayOutcome::Close(None)
+        );
+    }
+
+    #[test]
+    fn test_draw_palette() -> Result<()> {
+        let mut palette = setup();
+        type_query(&mut palette, "p");
+        let mut terminal = Terminal::new(TestBackend::new(72, 10))?;
+
+        terminal.draw(|f| palette.draw(f, f.area(), &Theme::default()))?;
+
+        assert_snapshot!(terminal.backend());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,118 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Padding, Paragraph, Wrap},
+    Frame,
+};
+use serde::{Deserialize, Serialize};
+
+use super::{popup_area, OverlayOutcome};
+use crate::shared::{actions::Action, theme::Theme};
+
+/// Asks a yes/no question and dispatches `on_confirm` when it is accepted.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ConfirmDialog {
+    pub message: String,
+    pub on_confirm: Box<Action>,
+    pub title: String,
+    is_yes_selected: bool,
+}
+
+impl ConfirmDialog {
+    pub fn new(title: &str, message: &str, on_confirm: Action) -> Self {
+        return ConfirmDialog {
+            message: message.to_owned(),
+            on_confirm: Box::new(on_confirm),
+            title: title.to_owned(),
+            is_yes_selected: false,
+        };
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> OverlayOutcome {
+        return match key.code {
+            KeyCode::Char('y') | KeyCode::Char('Y') => self.confirm(),
+            KeyCode::Char('n') | KeyCode::Char('N') | KeyCode::Esc => OverlayOutcome::Close(None),
+            KeyCode::Enter if self.is_yes_selected => self.confirm(),
+            KeyCode::Enter => OverlayOutcome::Close(None),
+            KeyCode::Left | KeyCode::Right | KeyCode::Tab | KeyCode::BackTab => {
+                self.is_yes_selected = !self.is_yes_selected;
+                OverlayOutcome::Open
+            }
+            _ => OverlayOutcome::Open,
+        };
+    }
+
+    fn confirm(&self) -> OverlayOutcome {
+        return OverlayOutcome::Close(Some(*self.on_confirm.clone()));
+    }
+
+    fn create_button(&self, label: &'static str, is_selected: bool) -> Span<'static> {
+        let style = match is_selected {
+            true => Style::default().add_modifier(Modifier::REVERSED),
+            false => Style::default(),
+        };
+
+        return Span::styled(label, style);
+    }
+
+    pub fn draw(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let width = (self.message.chars().count() as u16 + 6).clamp(30, 60);
+        let area = popup_area(area, width, 5);
+
+        let block = Block::default()
+            .title(format!("─{}─", self.title))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(theme.border_style(true))
+            .padding(Padding::horizontal(1));
+
+        let buttons = Line::from(vec![
+            self.create_button(" [Y]es ", self.is_yes_selected),
+            Span::raw("  "),
+            self.create_button(" [N]o ", !self.is_yes_selected),
+        ])
+        .alignment(Alignment::Center);
+
+        let text = vec![Line::from(self.message.as_str()), Line::default(), buttons];
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(
+            Paragraph::new(text).wrap(Wrap { trim: true }).block(block),
+            area,
+        );
+    }
+}
+
+#[cfg(test)]
+mod confirm_dialog_tests {
+    use super::ConfirmDialog;
+
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use pretty_assertions::assert_eq;
+    use test_case::test_case;
+
+    use crate::shared::{actions::Action, overlay::OverlayOutcome};
+
+    fn key(code: KeyCode) -> KeyEvent {
+        return KeyEvent::new(code, KeyModifiers::empty());
+    }
+
+    #[test_case(vec![KeyCode::Char('y')], OverlayOutcome::Close(Some(Action::Quit)); "Yes")]
+    #[test_case(vec![KeyCode::Char('n')], OverlayOutcome::Close(None); "No")]
+    #[test_case(vec![KeyCode::Esc], OverlayOutcome::Close(None); "Escape")]
+    #[test_case(vec![KeyCode::Enter], OverlayOutcome::Close(None); "Enter Defaults To No")]
+    #[test_case(vec![KeyCode::Left, KeyCode::Enter], OverlayOutcome::Close(Some(Action::Quit)); "Enter On Yes")]
+    #[test_case(vec![KeyCode::Char('x')], OverlayOutcome::Open; "Other Keys Keep It Open")]
+    fn test_handle_key(codes: Vec<KeyCode>, expected: OverlayOutcome) {
+        let mut dialog = ConfirmDialog::new("Quit", "Are you sure?", Action::Quit);
+
+        let outcome = codes
+            .into_iter()
+            .map(|code| dialog.handle_key(key(code)))
+            .last();
+
+        assert_eq!(outcome, Some(expected));
+    }
+}
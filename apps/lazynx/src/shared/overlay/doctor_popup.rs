@@ -0,0 +1,190 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Padding, Paragraph},
+    Frame,
+};
+use serde::{Deserialize, Serialize};
+
+use super::{popup_area, OverlayOutcome};
+use crate::shared::theme::Theme;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum CheckStatus {
+    Pass,
+    Warn,
+    Fail,
+}
+
+impl CheckStatus {
+    pub fn label(self) -> &'static str {
+        return match self {
+            CheckStatus::Pass => "pass",
+            CheckStatus::Warn => "warn",
+            CheckStatus::Fail => "fail",
+        };
+    }
+
+    fn style(self, theme: &Theme) -> Style {
+        let color = match self {
+            CheckStatus::Pass => theme.accent,
+            CheckStatus::Warn => theme.highlight,
+            CheckStatus::Fail => theme.error,
+        };
+
+        return Style::default().fg(color).add_modifier(Modifier::BOLD);
+    }
+}
+
+/// The outcome of one of the doctor's checks, e.g. whether Node is installed.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Check {
+    pub detail: String,
+    pub name: String,
+    pub status: CheckStatus,
+}
+
+impl Check {
+    pub fn new(name: &str, status: CheckStatus, detail: &str) -> Self {
+        return Check {
+            detail: detail.to_owned(),
+            name: name.to_owned(),
+            status,
+        };
+    }
+
+    pub fn pass(name: &str, detail: &str) -> Self {
+        return Check::new(name, CheckStatus::Pass, detail);
+    }
+
+    pub fn warn(name: &str, detail: &str) -> Self {
+        return Check::new(name, CheckStatus::Warn, detail);
+    }
+
+    pub fn fail(name: &str, detail: &str) -> Self {
+        return Check::new(name, CheckStatus::Fail, detail);
+    }
+}
+
+/// A scrollable list of the doctor's checks, each marked pass, warn or fail.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct DoctorPopup {
+    pub checks: Vec<Check>,
+    pub scroll: u16,
+}
+
+impl DoctorPopup {
+    pub fn new(checks: Vec<Check>) -> Self {
+        return DoctorPopup { checks, scroll: 0 };
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> OverlayOutcome {
+        let last_line = self.checks.len().saturating_sub(1) as u16;
+
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => return OverlayOutcome::Close(None),
+            KeyCode::Down | KeyCode::Char('j') => self.scroll = (self.scroll + 1).min(last_line),
+            KeyCode::Up | KeyCode::Char('k') => self.scroll = self.scroll.saturating_sub(1),
+            _ => {}
+        }
+
+        return OverlayOutcome::Open;
+    }
+
+    fn create_lines(&self, theme: &Theme) -> Vec<Line<'static>> {
+        let name_width = self
+            .checks
+            .iter()
+            .map(|check| check.name.chars().count())
+            .max()
+            .unwrap_or_default();
+
+        return self
+            .checks
+            .iter()
+            .map(|check| {
+                Line::from(vec![
+                    Span::styled(
+                        format!("[{}]", check.status.label()),
+                        check.status.style(theme),
+                    ),
+                    Span::raw(format!(" {:<name_width$}  ", check.name)),
+                    Span::raw(check.detail.clone()),
+                ])
+            })
+            .collect();
+    }
+
+    pub fn draw(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let lines = self.create_lines(theme);
+        let area = popup_area(area, 80, lines.len() as u16 + 2);
+
+        let block = Block::default()
+            .title("─Doctor─")
+            .title_bottom(Line::from("─j/k scroll─esc close─").right_aligned())
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(theme.border_style(true))
+            .padding(Padding::horizontal(1));
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(
+            Paragraph::new(lines).scroll((self.scroll, 0)).block(block),
+            area,
+        );
+    }
+}
+
+#[cfg(test)]
+mod doctor_popup_tests {
+    use super::{Check, DoctorPopup};
+
+    use color_eyre::eyre::Result;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use insta::assert_snapshot;
+    use pretty_assertions::assert_eq;
+    use ratatui::{backend::TestBackend, Terminal};
+
+    use crate::shared::{overlay::OverlayOutcome, theme::Theme};
+
+    fn setup() -> DoctorPopup {
+        return DoctorPopup::new(vec![
+            Check::pass("Node.js", "v20.11.0"),
+            Check::warn("Nx daemon", "Turned off by NX_DAEMON=false"),
+            Check::fail("nx.json", "expected value at line 1 column 1"),
+        ]);
+    }
+
+    fn key(code: KeyCode) -> KeyEvent {
+        return KeyEvent::new(code, KeyModifiers::empty());
+    }
+
+    #[test]
+    fn test_draw_popup() -> Result<()> {
+        let popup = setup();
+        let mut terminal = Terminal::new(TestBackend::new(84, 7))?;
+
+        terminal.draw(|f| popup.draw(f, f.area(), &Theme::default()))?;
+
+        assert_snapshot!(terminal.backend());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_scroll_and_close() {
+        let mut popup = setup();
+
+        popup.handle_key(key(KeyCode::Down));
+        popup.handle_key(key(KeyCode::Down));
+        popup.handle_key(key(KeyCode::Down));
+        assert_eq!(popup.scroll, 2);
+
+        assert_eq!(
+            popup.handle_key(key(KeyCode::Esc)),
+            OverlayOutcome::Close(None)
+        );
+    }
+}
@@ -0,0 +1,59 @@
+use crossterm::event::KeyEvent;
+use ratatui::{
+    layout::Rect,
+    style::Style,
+    text::Line,
+    widgets::{Block, BorderType, Borders, Clear, Padding, Paragraph, Wrap},
+    Frame,
+};
+use serde::{Deserialize, Serialize};
+
+use super::{popup_area, OverlayOutcome};
+use crate::shared::theme::Theme;
+
+/// Shows an error until any key is pressed.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ErrorBox {
+    pub message: String,
+    pub title: String,
+}
+
+impl ErrorBox {
+    pub fn new(title: &str, message: &str) -> Self {
+        return ErrorBox {
+            message: message.to_owned(),
+            title: title.to_owned(),
+        };
+    }
+
+    pub fn handle_key(&mut self, _key: KeyEvent) -> OverlayOutcome {
+        return OverlayOutcome::Close(None);
+    }
+
+    pub fn draw(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        const WIDTH: u16 = 60;
+        let text_width = WIDTH as usize - 4;
+        let message_lines: usize = self
+            .message
+            .lines()
+            .map(|line| line.chars().count().div_ceil(text_width).max(1))
+            .sum();
+        let area = popup_area(area, WIDTH, message_lines as u16 + 4);
+
+        let block = Block::default()
+            .title(format!("─{}─", self.title))
+            .title_bottom(Line::from("─press any key to dismiss─").right_aligned())
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.error))
+            .padding(Padding::new(1, 1, 1, 0));
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(
+            Paragraph::new(self.message.as_str())
+                .wrap(Wrap { trim: false })
+                .block(block),
+            area,
+        );
+    }
+}
@@ -0,0 +1,244 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Clear, Padding, Paragraph},
+    Frame,
+};
+use serde::{Deserialize, Serialize};
+
+use super::{popup_area, OverlayOutcome};
+use crate::shared::{
+    actions::Action,
+    keymap::{format_key, KeyBinding, KeyContext, Keymap},
+    theme::Theme,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct HelpSection {
+    pub bindings: Vec<KeyBinding>,
+    pub title: String,
+}
+
+/// A scrollable list of key bindings, grouped into sections.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct HelpPopup {
+    /// The keys that scroll the popup and close it again, taken from the
+    /// keymap so they follow the user's bindings.
+    pub controls: Vec<KeyBinding>,
+    pub scroll: u16,
+    pub sections: Vec<HelpSection>,
+}
+
+impl HelpPopup {
+    /// Scrolls like the main pane and closes on the keys that opened it, or
+    /// on Esc.
+    pub fn new(sections: Vec<HelpSection>, keymap: &Keymap) -> Self {
+        let opens_help = |binding: &&KeyBinding| binding.action == Action::Help;
+        let scrolls =
+            |binding: &&KeyBinding| matches!(binding.action, Action::ScrollDown | Action::ScrollUp);
+
+        let controls = keymap
+            .bindings_for(KeyContext::Global)
+            .iter()
+            .filter(opens_help)
+            .chain(keymap.bindings_for(KeyContext::Main).iter().filter(scrolls))
+            .cloned()
+            .collect();
+
+        return HelpPopup {
+            controls,
+            scroll: 0,
+            sections,
+        };
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> OverlayOutcome {
+        let last_line = self.create_lines().len().saturating_sub(1) as u16;
+
+        let action = self
+            .controls
+            .iter()
+            .find(|binding| binding.matches(&key))
+            .map(|binding| &binding.action);
+
+        match (key.code, action) {
+            (KeyCode::Esc, _) | (_, Some(Action::Help)) => return OverlayOutcome::Close(None),
+            (_, Some(Action::ScrollDown)) => self.scroll = (self.scroll + 1).min(last_line),
+            (_, Some(Action::ScrollUp)) => self.scroll = self.scroll.saturating_sub(1),
+            (KeyCode::PageDown, _) => self.scroll = (self.scroll + 10).min(last_line),
+            (KeyCode::PageUp, _) => self.scroll = self.scroll.saturating_sub(10),
+            _ => {}
+        }
+
+        return OverlayOutcome::Open;
+    }
+
+    /// Names the first key of each control, e.g. `─j/k scroll─esc close─`.
+    fn create_footer(&self) -> String {
+        let first_key = |action: Action| {
+            self.controls
+                .iter()
+                .find(|binding| binding.action == action)
+                .map(|binding| format_key(&binding.key))
+        };
+
+        return match (first_key(Action::ScrollDown), first_key(Action::ScrollUp)) {
+            (Some(down), Some(up)) => format!("─{down}/{up} scroll─esc close─"),
+            _ => String::from("─esc close─"),
+        };
+    }
+
+    fn create_lines(&self) -> Vec<Line<'static>> {
+        let key_width = self
+            .sections
+            .iter()
+            .flat_map(|section| &section.bindings)
+            .map(|binding| format_key(&binding.key).chars().count())
+            .max()
+            .unwrap_or_default();
+
+        let mut lines = Vec::new();
+
+        for section in &self.sections {
+            if !lines.is_empty() {
+                lines.push(Line::default());
+            }
+
+            lines.push(Line::from(Span::styled(
+                section.title.clone(),
+                Style::default().add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
+            )));
+
+            for binding in &section.bindings {
+                lines.push(Line::from(vec![
+                    Span::styled(
+                        format!("{:<key_width$}", format_key(&binding.key)),
+                        Style::default().add_modifier(Modifier::BOLD),
+                    ),
+                    Span::raw("  "),
+                    Span::raw(binding.description()),
+                ]));
+            }
+        }
+
+        return lines;
+    }
+
+    pub fn draw(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let lines = self.create_lines();
+        let area = popup_area(area, 60, lines.len() as u16 + 2);
+
+        let block = Block::default()
+            .title("─Help─")
+            .title_bottom(Line::from(self.create_footer()).right_aligned())
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(theme.border_style(true))
+            .padding(Padding::horizontal(1));
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(
+            Paragraph::new(lines).scroll((self.scroll, 0)).block(block),
+            area,
+        );
+    }
+}
+
+#[cfg(test)]
+mod help_popup_tests {
+    use super::{HelpPopup, HelpSection};
+
+    use std::collections::BTreeMap;
+
+    use color_eyre::eyre::Result;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use insta::assert_snapshot;
+    use pretty_assertions::assert_eq;
+    use ratatui::{backend::TestBackend, Terminal};
+    use test_case::test_case;
+
+    use crate::shared::{
+        keymap::{KeyContext, Keymap},
+        overlay::OverlayOutcome,
+        theme::Theme,
+    };
+
+    fn setup() -> HelpPopup {
+        let keymap = Keymap::default();
+
+        return HelpPopup::new(
+            vec![
+                HelpSection {
+                    bindings: keymap.bindings_for(KeyContext::Projects).to_vec(),
+                    title: String::from("Projects"),
+                },
+                HelpSection {
+                    bindings: keymap.bindings_for(KeyContext::Global).to_vec(),
+                    title: String::from("Global"),
+                },
+            ],
+            &keymap,
+        );
+    }
+
+    fn key(code: KeyCode) -> KeyEvent {
+        return KeyEvent::new(code, KeyModifiers::empty());
+    }
+
+    #[test]
+    fn test_draw_popup() -> Result<()> {
+        let popup = setup();
+        let mut terminal = Terminal::new(TestBackend::new(64, 22))?;
+
+        terminal.draw(|f| popup.draw(f, f.area(), &Theme::default()))?;
+
+        assert_snapshot!(terminal.backend());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_scroll() {
+        let mut popup = setup();
+
+        popup.handle_key(key(KeyCode::Down));
+        popup.handle_key(key(KeyCode::Down));
+        popup.handle_key(key(KeyCode::Up));
+        assert_eq!(popup.scroll, 1);
+
+        popup.handle_key(key(KeyCode::PageUp));
+        assert_eq!(popup.scroll, 0);
+    }
+
+    #[test_case(KeyCode::Esc; "Escape")]
+    #[test_case(KeyCode::Char('?'); "Help Key")]
+    fn test_close(code: KeyCode) {
+        let mut popup = setup();
+
+        assert_eq!(popup.handle_key(key(code)), OverlayOutcome::Close(None));
+    }
+
+    #[test]
+    fn test_controls_follow_keymap() -> Result<()> {
+        let keybindings = BTreeMap::from([(
+            KeyContext::Main,
+            BTreeMap::from([
+                (String::from("n"), String::from("ScrollDown")),
+                (String::from("j"), String::from("FocusNext")),
+            ]),
+        )]);
+        let keymap = Keymap::default().with_keybindings(&keybindings)?;
+        let mut popup = HelpPopup::new(setup().sections, &keymap);
+
+        popup.handle_key(key(KeyCode::Char('j')));
+        assert_eq!(popup.scroll, 0, "j should no longer scroll.");
+
+        popup.handle_key(key(KeyCode::Char('n')));
+        assert_eq!(popup.scroll, 1, "n should scroll down.");
+        assert_eq!(popup.create_footer(), "─Down/k scroll─esc close─");
+
+        Ok(())
+    }
+}
@@ -0,0 +1,29 @@
+mod command_palette;
+pub use command_palette::{CommandPalette, PaletteEntry};
+
+mod confirm_dialog;
+pub use confirm_dialog::ConfirmDialog;
+
+mod doctor_popup;
+pub use doctor_popup::{Check, CheckStatus, DoctorPopup};
+
+mod error_box;
+pub use error_box::ErrorBox;
+
+mod help_popup;
+pub use help_popup::{HelpPopup, HelpSection};
+
+mod overlay;
+pub use overlay::{Overlay, OverlayOutcome};
+
+mod overlay_stack;
+pub use overlay_stack::OverlayStack;
+
+mod popup_area;
+pub use popup_area::popup_area;
+
+mod select_menu;
+pub use select_menu::{SelectItem, SelectMenu};
+
+mod text_prompt;
+pub use text_prompt::TextPrompt;
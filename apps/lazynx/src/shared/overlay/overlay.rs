@@ -0,0 +1,56 @@
+use crossterm::event::KeyEvent;
+use ratatui::{layout::Rect, Frame};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    CommandPalette, ConfirmDialog, DoctorPopup, ErrorBox, HelpPopup, SelectMenu, TextPrompt,
+};
+use crate::shared::{actions::Action, theme::Theme};
+
+/// What an overlay wants to happen after it handled a key press.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum OverlayOutcome {
+    /// The overlay stays open.
+    Open,
+    /// The overlay is dismissed, optionally dispatching an action.
+    Close(Option<Action>),
+}
+
+/// A popup drawn above the rest of the UI that captures input until it is
+/// dismissed.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Overlay {
+    Confirm(ConfirmDialog),
+    Doctor(DoctorPopup),
+    Error(ErrorBox),
+    Help(HelpPopup),
+    Palette(CommandPalette),
+    Prompt(TextPrompt),
+    Select(SelectMenu),
+}
+
+impl Overlay {
+    pub fn handle_key(&mut self, key: KeyEvent) -> OverlayOutcome {
+        return match self {
+            Overlay::Confirm(dialog) => dialog.handle_key(key),
+            Overlay::Doctor(doctor) => doctor.handle_key(key),
+            Overlay::Error(error) => error.handle_key(key),
+            Overlay::Help(help) => help.handle_key(key),
+            Overlay::Palette(palette) => palette.handle_key(key),
+            Overlay::Prompt(prompt) => prompt.handle_key(key),
+            Overlay::Select(menu) => menu.handle_key(key),
+        };
+    }
+
+    pub fn draw(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        match self {
+            Overlay::Confirm(dialog) => dialog.draw(frame, area, theme),
+            Overlay::Doctor(doctor) => doctor.draw(frame, area, theme),
+            Overlay::Error(error) => error.draw(frame, area, theme),
+            Overlay::Help(help) => help.draw(frame, area, theme),
+            Overlay::Palette(palette) => palette.draw(frame, area, theme),
+            Overlay::Prompt(prompt) => prompt.draw(frame, area, theme),
+            Overlay::Select(menu) => menu.draw(frame, area, theme),
+        }
+    }
+}
@@ -0,0 +1,129 @@
+use crossterm::event::Event as CrosstermEvent;
+use ratatui::{layout::Rect, Frame};
+
+use super::{Overlay, OverlayOutcome};
+use crate::shared::{actions::Action, events::Event, theme::Theme};
+
+/// The overlays currently open, drawn bottom to top. Only the topmost overlay
+/// receives input.
+#[derive(Default)]
+pub struct OverlayStack {
+    overlays: Vec<Overlay>,
+}
+
+impl OverlayStack {
+    pub fn push(&mut self, overlay: Overlay) {
+        self.overlays.push(overlay);
+    }
+
+    pub fn pop(&mut self) -> Option<Overlay> {
+        return self.overlays.pop();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        return self.overlays.is_empty();
+    }
+
+    #[cfg(test)]
+    pub fn top(&self) -> Option<&Overlay> {
+        return self.overlays.last();
+    }
+
+    /// Hands key presses to the topmost overlay, closing it when it asks to.
+    /// Every other event is swallowed so nothing underneath reacts to it.
+    pub fn handle_events(&mut self, event: Event) -> Option<Action> {
+        let Event::Crossterm(CrosstermEvent::Key(key)) = event else {
+            return None;
+        };
+
+        let outcome = self.overlays.last_mut()?.handle_key(key);
+
+        let OverlayOutcome::Close(action) = outcome else {
+            return None;
+        };
+
+        self.overlays.pop();
+
+        return action;
+    }
+
+    pub fn draw(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        for overlay in &self.overlays {
+            overlay.draw(frame, area, theme);
+        }
+    }
+}
+
+#[cfg(test)]
+mod overlay_stack_tests {
+    use super::OverlayStack;
+
+    use color_eyre::eyre::Result;
+    use crossterm::event::{Event as CrosstermEvent, KeyCode, KeyEvent, KeyModifiers};
+    use insta::assert_snapshot;
+    use pretty_assertions::assert_eq;
+    use ratatui::widgets::{Block, Borders};
+    use test_utils::WidgetTestBed;
+
+    use crate::shared::{
+        actions::Action,
+        events::Event,
+        overlay::{ConfirmDialog, ErrorBox, Overlay},
+        theme::Theme,
+    };
+
+    fn key_event(code: KeyCode) -> Event {
+        return Event::Crossterm(CrosstermEvent::Key(KeyEvent::new(
+            code,
+            KeyModifiers::empty(),
+        )));
+    }
+
+    fn confirm_quit() -> Overlay {
+        return Overlay::Confirm(ConfirmDialog::new("Quit", "Quit lazynx?", Action::Quit));
+    }
+
+    #[test]
+    fn test_only_top_overlay_receives_input() {
+        let mut stack = OverlayStack::default();
+        stack.push(confirm_quit());
+        stack.push(Overlay::Error(ErrorBox::new("Error", "Oops")));
+
+        let response = stack.handle_events(key_event(KeyCode::Char('y')));
+
+        assert_eq!(response, None, "The error box should have been dismissed.");
+        assert_eq!(stack.top(), Some(&confirm_quit()));
+
+        let response = stack.handle_events(key_event(KeyCode::Char('y')));
+
+        assert_eq!(response, Some(Action::Quit));
+        assert!(stack.is_empty(), "All overlays should have been closed.");
+    }
+
+    #[test]
+    fn test_other_events_are_swallowed() {
+        let mut stack = OverlayStack::default();
+        stack.push(confirm_quit());
+
+        let response = stack.handle_events(Event::Crossterm(CrosstermEvent::FocusLost));
+
+        assert_eq!(response, None);
+        assert!(!stack.is_empty(), "The overlay should still be open.");
+    }
+
+    #[test]
+    fn test_draw_above_content() -> Result<()> {
+        let mut test_bed = WidgetTestBed::<OverlayStack>::new(40, 10);
+        test_bed.widget.push(confirm_quit());
+
+        let stack = &test_bed.widget;
+        test_bed.terminal.draw(|f| {
+            f.render_widget(Block::default().borders(Borders::ALL), f.area());
+            stack.draw(f, f.area(), &Theme::default());
+        })?;
+
+        assert_snapshot!(test_bed.terminal.backend());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,35 @@
+use ratatui::layout::{Constraint, Flex, Layout, Rect};
+
+/// Centers a popup of the given size within the area, shrinking it to fit.
+pub fn popup_area(area: Rect, width: u16, height: u16) -> Rect {
+    let [area] = Layout::horizontal([Constraint::Length(width.min(area.width))])
+        .flex(Flex::Center)
+        .areas(area);
+    let [area] = Layout::vertical([Constraint::Length(height.min(area.height))])
+        .flex(Flex::Center)
+        .areas(area);
+
+    return area;
+}
+
+#[cfg(test)]
+mod popup_area_tests {
+    use super::popup_area;
+
+    use pretty_assertions::assert_eq;
+    use ratatui::layout::Rect;
+
+    #[test]
+    fn test_popup_is_centered() {
+        let area = popup_area(Rect::new(0, 0, 100, 50), 40, 10);
+
+        assert_eq!(area, Rect::new(30, 20, 40, 10));
+    }
+
+    #[test]
+    fn test_popup_shrinks_to_fit() {
+        let area = popup_area(Rect::new(0, 0, 20, 5), 40, 10);
+
+        assert_eq!(area, Rect::new(0, 0, 20, 5));
+    }
+}
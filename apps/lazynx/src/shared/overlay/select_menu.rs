@@ -0,0 +1,135 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    widgets::{Block, BorderType, Borders, Clear, List, ListState},
+    Frame,
+};
+use serde::{Deserialize, Serialize};
+
+use super::{popup_area, OverlayOutcome};
+use crate::shared::{actions::Action, theme::Theme};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SelectItem {
+    pub action: Action,
+    pub label: String,
+}
+
+impl SelectItem {
+    pub fn new(label: &str, action: Action) -> Self {
+        return SelectItem {
+            action,
+            label: label.to_owned(),
+        };
+    }
+}
+
+/// A list of choices, each dispatching its own action when picked.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SelectMenu {
+    pub items: Vec<SelectItem>,
+    pub selected: usize,
+    pub title: String,
+}
+
+impl SelectMenu {
+    pub fn new(title: &str, items: Vec<SelectItem>) -> Self {
+        return SelectMenu {
+            items,
+            selected: 0,
+            title: title.to_owned(),
+        };
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> OverlayOutcome {
+        let last = self.items.len().saturating_sub(1);
+
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => return OverlayOutcome::Close(None),
+            KeyCode::Enter => {
+                let action = self
+                    .items
+                    .get(self.selected)
+                    .map(|item| item.action.clone());
+                return OverlayOutcome::Close(action);
+            }
+            KeyCode::Down | KeyCode::Char('j') => self.selected = (self.selected + 1).min(last),
+            KeyCode::Up | KeyCode::Char('k') => self.selected = self.selected.saturating_sub(1),
+            _ => {}
+        }
+
+        return OverlayOutcome::Open;
+    }
+
+    pub fn draw(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let width = self
+            .items
+            .iter()
+            .map(|item| item.label.chars().count())
+            .chain([self.title.chars().count()])
+            .max()
+            .unwrap_or_default() as u16
+            + 6;
+        let area = popup_area(area, width.max(30), self.items.len() as u16 + 2);
+
+        let block = Block::default()
+            .title(format!("─{}─", self.title))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(theme.border_style(true));
+
+        let list = List::new(self.items.iter().map(|item| format!(" {}", item.label)))
+            .block(block)
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+        let mut state = ListState::default().with_selected(Some(self.selected));
+
+        frame.render_widget(Clear, area);
+        frame.render_stateful_widget(list, area, &mut state);
+    }
+}
+
+#[cfg(test)]
+mod select_menu_tests {
+    use super::{SelectItem, SelectMenu};
+
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use pretty_assertions::assert_eq;
+
+    use crate::shared::{actions::Action, overlay::OverlayOutcome};
+
+    fn key(code: KeyCode) -> KeyEvent {
+        return KeyEvent::new(code, KeyModifiers::empty());
+    }
+
+    fn setup() -> SelectMenu {
+        return SelectMenu::new(
+            "Pick",
+            vec![
+                SelectItem::new("Help", Action::Help),
+                SelectItem::new("Quit", Action::Quit),
+            ],
+        );
+    }
+
+    #[test]
+    fn test_select_item() {
+        let mut menu = setup();
+
+        menu.handle_key(key(KeyCode::Down));
+        menu.handle_key(key(KeyCode::Down));
+        let outcome = menu.handle_key(key(KeyCode::Enter));
+
+        assert_eq!(outcome, OverlayOutcome::Close(Some(Action::Quit)));
+    }
+
+    #[test]
+    fn test_escape_cancels() {
+        let mut menu = setup();
+
+        assert_eq!(
+            menu.handle_key(key(KeyCode::Esc)),
+            OverlayOutcome::Close(None)
+        );
+    }
+}
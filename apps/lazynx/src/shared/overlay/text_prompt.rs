@@ -0,0 +1,124 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    layout::{Position, Rect},
+    widgets::{Block, BorderType, Borders, Clear, Paragraph},
+    Frame,
+};
+use serde::{Deserialize, Serialize};
+
+use super::{popup_area, OverlayOutcome};
+use crate::shared::{actions::Action, theme::Theme};
+
+/// A single-line text input. Submitting it dispatches
+/// `Action::PromptSubmitted` with the prompt's `id` so the component that
+/// opened it can pick up the value.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TextPrompt {
+    pub id: String,
+    pub input: String,
+    pub title: String,
+}
+
+impl TextPrompt {
+    pub fn new(id: &str, title: &str) -> Self {
+        return TextPrompt {
+            id: id.to_owned(),
+            input: String::new(),
+            title: title.to_owned(),
+        };
+    }
+
+    pub fn with_input(mut self, input: &str) -> Self {
+        self.input = input.to_owned();
+        return self;
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> OverlayOutcome {
+        match key.code {
+            KeyCode::Esc => return OverlayOutcome::Close(None),
+            KeyCode::Enter => {
+                return OverlayOutcome::Close(Some(Action::PromptSubmitted {
+                    id: self.id.clone(),
+                    value: self.input.clone(),
+                }))
+            }
+            KeyCode::Backspace => {
+                self.input.pop();
+            }
+            KeyCode::Char(char) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.input.push(char);
+            }
+            _ => {}
+        }
+
+        return OverlayOutcome::Open;
+    }
+
+    pub fn draw(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let area = popup_area(area, 50, 3);
+
+        let block = Block::default()
+            .title(format!("─{}─", self.title))
+            .borders(Borders::ALL)
+            .border_type(BorderType::Rounded)
+            .border_style(theme.border_style(true));
+
+        // Keep the end of the input, where the cursor is, in view.
+        let inner_width = area.width.saturating_sub(3) as usize;
+        let input_width = self.input.chars().count();
+        let visible: String = self
+            .input
+            .chars()
+            .skip(input_width.saturating_sub(inner_width))
+            .collect();
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(Paragraph::new(format!(" {visible}")).block(block), area);
+        frame.set_cursor_position(Position::new(
+            area.x + 2 + visible.chars().count() as u16,
+            area.y + 1,
+        ));
+    }
+}
+
+#[cfg(test)]
+mod text_prompt_tests {
+    use super::TextPrompt;
+
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+    use pretty_assertions::assert_eq;
+
+    use crate::shared::{actions::Action, overlay::OverlayOutcome};
+
+    fn key(code: KeyCode) -> KeyEvent {
+        return KeyEvent::new(code, KeyModifiers::empty());
+    }
+
+    #[test]
+    fn test_typing_and_submitting() {
+        let mut prompt = TextPrompt::new("rename", "Rename").with_input("ap");
+
+        prompt.handle_key(key(KeyCode::Char('x')));
+        prompt.handle_key(key(KeyCode::Backspace));
+        prompt.handle_key(key(KeyCode::Char('i')));
+        let outcome = prompt.handle_key(key(KeyCode::Enter));
+
+        assert_eq!(
+            outcome,
+            OverlayOutcome::Close(Some(Action::PromptSubmitted {
+                id: String::from("rename"),
+                value: String::from("api"),
+            }))
+        );
+    }
+
+    #[test]
+    fn test_escape_cancels() {
+        let mut prompt = TextPrompt::new("rename", "Rename");
+
+        assert_eq!(
+            prompt.handle_key(key(KeyCode::Esc)),
+            OverlayOutcome::Close(None)
+        );
+    }
+}
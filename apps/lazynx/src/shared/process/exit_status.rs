@@ -0,0 +1,42 @@
+use std::{os::unix::process::ExitStatusExt, process};
+
+use serde::{Deserialize, Serialize};
+
+/// How a child process ended.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Hash)]
+pub enum ExitStatus {
+    /// The process exited with the code.
+    Code(i32),
+
+    /// The process was terminated by the signal.
+    Signal(i32),
+}
+
+impl ExitStatus {
+    /// The exit code, or `None` if the process was terminated by a signal.
+    pub fn code(&self) -> Option<i32> {
+        return match self {
+            ExitStatus::Code(code) => Some(*code),
+            ExitStatus::Signal(_) => None,
+        };
+    }
+
+    /// The status a shell reports for the process: its exit code, or 128 plus
+    /// the number of the signal that terminated it.
+    pub fn shell_status(&self) -> i32 {
+        return match self {
+            ExitStatus::Code(code) => *code,
+            ExitStatus::Signal(signal) => 128 + signal,
+        };
+    }
+}
+
+impl From<process::ExitStatus> for ExitStatus {
+    fn from(status: process::ExitStatus) -> Self {
+        // A process without an exit code was terminated by a signal.
+        return match status.code() {
+            Some(code) => ExitStatus::Code(code),
+            None => ExitStatus::Signal(status.signal().unwrap_or_default()),
+        };
+    }
+}
@@ -0,0 +1,16 @@
+mod exit_status;
+pub use exit_status::ExitStatus;
+
+mod process_event;
+pub use process_event::ProcessEvent;
+
+mod process_handler;
+pub use process_handler::ProcessHandler;
+
+mod process_id;
+pub use process_id::ProcessId;
+
+mod process_output;
+pub use process_output::ProcessOutput;
+
+mod spawn_process;
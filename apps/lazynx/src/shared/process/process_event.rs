@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+use super::ExitStatus;
+
+/// Something a child process spawned with `spawn_process` did.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Hash)]
+pub enum ProcessEvent {
+    /// A line the process wrote to stdout.
+    Stdout(String),
+
+    /// A line the process wrote to stderr.
+    Stderr(String),
+
+    /// The process exited, or was terminated.
+    Exited(ExitStatus),
+}
@@ -0,0 +1,103 @@
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc,
+};
+
+use color_eyre::eyre::Result;
+use tokio::{process::Command, sync::mpsc::UnboundedSender};
+use tokio_util::{sync::CancellationToken, task::TaskTracker};
+
+use super::{spawn_process::spawn_process, ProcessId, ProcessOutput};
+
+/// Spawns child processes on behalf of components and keeps track of them, so
+/// they can all be terminated before lazynx exits.
+#[derive(Clone)]
+pub struct ProcessHandler {
+    cancellation_token: CancellationToken,
+    /// The id of the next process, shared by every clone of the handler.
+    next_id: Arc<AtomicU64>,
+    tracker: TaskTracker,
+    tx: UnboundedSender<ProcessOutput>,
+}
+
+impl ProcessHandler {
+    pub fn new(tx: UnboundedSender<ProcessOutput>) -> Self {
+        return Self {
+            cancellation_token: CancellationToken::new(),
+            next_id: Arc::new(AtomicU64::new(0)),
+            tracker: TaskTracker::new(),
+            tx,
+        };
+    }
+
+    /// Spawns the command, streaming its output to the `Runner`. Returns the
+    /// id its output is tagged with.
+    pub fn spawn(&self, command: Command) -> Result<ProcessId> {
+        let id = ProcessId(self.next_id.fetch_add(1, Ordering::Relaxed));
+        let process = spawn_process(
+            id,
+            command,
+            self.tx.clone(),
+            self.cancellation_token.child_token(),
+        )?;
+        self.tracker.spawn(process);
+
+        return Ok(id);
+    }
+
+    /// Terminates every running process and waits for them to exit.
+    pub async fn terminate(&self) {
+        self.tracker.close();
+        self.cancellation_token.cancel();
+        self.tracker.wait().await;
+    }
+}
+
+#[cfg(test)]
+mod process_handler_tests {
+    use super::ProcessHandler;
+
+    use color_eyre::eyre::Result;
+    use pretty_assertions::{assert_eq, assert_ne};
+    use tokio::{
+        process::Command,
+        sync::mpsc::unbounded_channel,
+        time::{timeout, Duration},
+    };
+
+    use crate::shared::process::{ExitStatus, ProcessEvent, ProcessOutput};
+
+    #[tokio::test]
+    async fn test_terminate_stops_running_processes() -> Result<()> {
+        let (tx, mut rx) = unbounded_channel();
+        let handler = ProcessHandler::new(tx);
+        let mut command = Command::new("sleep");
+        command.arg("30");
+
+        let id = handler.spawn(command)?;
+        timeout(Duration::from_secs(5), handler.terminate()).await?;
+
+        assert_eq!(
+            rx.try_recv()?,
+            ProcessOutput {
+                id,
+                event: ProcessEvent::Exited(ExitStatus::Signal(libc::SIGTERM))
+            }
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_spawned_processes_get_their_own_ids() -> Result<()> {
+        let (tx, _rx) = unbounded_channel();
+        let handler = ProcessHandler::new(tx);
+
+        let first = handler.spawn(Command::new("true"))?;
+        let second = handler.clone().spawn(Command::new("true"))?;
+
+        assert_ne!(first, second);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,6 @@
+use serde::{Deserialize, Serialize};
+
+/// Tells apart the processes spawned by a `ProcessHandler`, so their output
+/// can be matched to the run that started them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct ProcessId(pub u64);
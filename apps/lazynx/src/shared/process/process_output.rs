@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+use super::{ProcessEvent, ProcessId};
+
+/// Output produced by a child process spawned with `spawn_process`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Hash)]
+pub struct ProcessOutput {
+    /// The process the output came from.
+    pub id: ProcessId,
+
+    pub event: ProcessEvent,
+}
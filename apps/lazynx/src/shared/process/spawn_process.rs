@@ -0,0 +1,282 @@
+use std::{process::Stdio, time::Duration};
+
+use color_eyre::eyre::Result;
+use tokio::{
+    io::{AsyncBufReadExt, AsyncRead, BufReader},
+    process::{Child, Command},
+    sync::mpsc::UnboundedSender,
+    task::JoinHandle,
+    time::{sleep, timeout},
+};
+use tokio_util::sync::CancellationToken;
+
+use super::{ProcessEvent, ProcessId, ProcessOutput};
+
+/// How long a child process is given to exit after `SIGTERM` before it is
+/// killed.
+const TERMINATE_GRACE_PERIOD: Duration = Duration::from_secs(2);
+
+/// How long the output of an exited process is still read, in case something
+/// outside its process group holds on to its pipes.
+const OUTPUT_DRAIN_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Spawns the command and streams its output, line by line, to `tx`, tagged
+/// with the `id`.
+///
+/// Once the process exits, whatever it left running in its process group is
+/// terminated and its exit status is sent as `ProcessEvent::Exited`.
+/// Cancelling the token terminates the process along with everything it
+/// spawned, since it is started in a process group of its own (Nx forks a
+/// process per task behind `npx`).
+pub fn spawn_process(
+    id: ProcessId,
+    mut command: Command,
+    tx: UnboundedSender<ProcessOutput>,
+    cancellation_token: CancellationToken,
+) -> Result<JoinHandle<Result<()>>> {
+    let mut child = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .process_group(0)
+        .spawn()?;
+
+    let stdout = child
+        .stdout
+        .take()
+        .map(|out| forward_lines(out, id, tx.clone(), ProcessEvent::Stdout));
+    let stderr = child
+        .stderr
+        .take()
+        .map(|err| forward_lines(err, id, tx.clone(), ProcessEvent::Stderr));
+    let readers = [stdout, stderr].into_iter().flatten();
+
+    return Ok(tokio::spawn(async move {
+        let process_group = child.id().map(|pid| pid as libc::pid_t);
+
+        let status = tokio::select! {
+            status = child.wait() => {
+                // Background processes, like a dev server, would otherwise
+                // keep the pipes open and the run going.
+                terminate_group(process_group).await;
+                for mut reader in readers {
+                    if timeout(OUTPUT_DRAIN_TIMEOUT, &mut reader).await.is_err() {
+                        reader.abort();
+                    }
+                }
+                status?
+            },
+            _ = cancellation_token.cancelled() => {
+                readers.for_each(|reader| reader.abort());
+                terminate(&mut child).await?
+            },
+        };
+
+        let _ = tx.send(ProcessOutput {
+            id,
+            event: ProcessEvent::Exited(status.into()),
+        });
+
+        Ok(())
+    }));
+}
+
+/// Asks the process group to exit with `SIGTERM`, killing it if the process
+/// is still running after the grace period.
+async fn terminate(child: &mut Child) -> Result<std::process::ExitStatus> {
+    let process_group = child.id().map(|pid| pid as libc::pid_t);
+    signal_group(process_group, libc::SIGTERM);
+
+    let status = match timeout(TERMINATE_GRACE_PERIOD, child.wait()).await {
+        Ok(status) => status?,
+        Err(_) => {
+            signal_group(process_group, libc::SIGKILL);
+            child.kill().await?;
+            child.wait().await?
+        }
+    };
+
+    // Children that ignored SIGTERM outlive the process itself.
+    signal_group(process_group, libc::SIGKILL);
+
+    return Ok(status);
+}
+
+/// Asks what is left of the process group to exit with `SIGTERM`, killing it
+/// if any of it is still running after the grace period.
+async fn terminate_group(process_group: Option<libc::pid_t>) {
+    if !signal_group(process_group, libc::SIGTERM) {
+        return;
+    }
+
+    let _ = timeout(TERMINATE_GRACE_PERIOD, async {
+        while signal_group(process_group, 0) {
+            sleep(Duration::from_millis(50)).await;
+        }
+    })
+    .await;
+
+    signal_group(process_group, libc::SIGKILL);
+}
+
+/// Sends the signal to every process in the group. Returns whether any
+/// process received it, so signal 0 checks whether the group still exists.
+fn signal_group(process_group: Option<libc::pid_t>, signal: libc::c_int) -> bool {
+    let Some(process_group) = process_group.filter(|pgid| *pgid > 0) else {
+        return false;
+    };
+
+    // SAFETY: `kill` has no memory safety requirements. The group id is
+    // positive, so the negated id only ever targets that group, never every
+    // process we may signal (-1) or our own group (0).
+    return unsafe { libc::kill(-process_group, signal) } == 0;
+}
+
+fn forward_lines<TReader: AsyncRead + Unpin + Send + 'static>(
+    reader: TReader,
+    id: ProcessId,
+    tx: UnboundedSender<ProcessOutput>,
+    into_event: fn(String) -> ProcessEvent,
+) -> JoinHandle<()> {
+    return tokio::spawn(async move {
+        let mut lines = BufReader::new(reader).lines();
+        while let Ok(Some(line)) = lines.next_line().await {
+            let _ = tx.send(ProcessOutput {
+                id,
+                event: into_event(line),
+            });
+        }
+    });
+}
+
+#[cfg(test)]
+mod spawn_process_tests {
+    use super::spawn_process;
+
+    use color_eyre::eyre::Result;
+    use pretty_assertions::assert_eq;
+    use tokio::{
+        process::Command,
+        sync::mpsc::unbounded_channel,
+        time::{sleep, timeout, Duration},
+    };
+    use tokio_util::sync::CancellationToken;
+
+    use crate::shared::process::{ExitStatus, ProcessEvent, ProcessId};
+
+    const ID: ProcessId = ProcessId(7);
+
+    #[tokio::test]
+    async fn test_spawn_process_streams_output_and_exit_code() -> Result<()> {
+        let (tx, mut rx) = unbounded_channel();
+        let mut command = Command::new("sh");
+        command.args(["-c", "echo out; echo err >&2; exit 3"]);
+
+        spawn_process(ID, command, tx, CancellationToken::new())?.await??;
+
+        let mut received = Vec::new();
+        while let Ok(output) = rx.try_recv() {
+            assert_eq!(output.id, ID);
+            received.push(output.event);
+        }
+
+        assert_eq!(received.len(), 3, "Unexpected output: {received:?}");
+        assert!(received.contains(&ProcessEvent::Stdout(String::from("out"))));
+        assert!(received.contains(&ProcessEvent::Stderr(String::from("err"))));
+        assert_eq!(
+            received.last(),
+            Some(&ProcessEvent::Exited(ExitStatus::Code(3)))
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_spawn_process_terminates_on_cancel() -> Result<()> {
+        let (tx, mut rx) = unbounded_channel();
+        let cancellation_token = CancellationToken::new();
+        let mut command = Command::new("sleep");
+        command.arg("30");
+
+        let handle = spawn_process(ID, command, tx, cancellation_token.clone())?;
+        cancellation_token.cancel();
+        handle.await??;
+
+        assert_eq!(
+            rx.recv().await.map(|output| output.event),
+            Some(ProcessEvent::Exited(ExitStatus::Signal(libc::SIGTERM))),
+            "Process should have been terminated by a signal."
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_spawn_process_terminates_children_on_cancel() -> Result<()> {
+        let (tx, mut rx) = unbounded_channel();
+        let cancellation_token = CancellationToken::new();
+        let mut command = Command::new("sh");
+        command.args(["-c", "sleep 30 & echo $!; wait"]);
+
+        let handle = spawn_process(ID, command, tx, cancellation_token.clone())?;
+        let Some(ProcessEvent::Stdout(child_pid)) = rx.recv().await.map(|output| output.event)
+        else {
+            panic!("The shell should have printed the pid of its child.");
+        };
+        cancellation_token.cancel();
+        handle.await??;
+        sleep(Duration::from_millis(100)).await;
+
+        // Exited children may linger as zombies until they are reaped.
+        let state = std::process::Command::new("ps")
+            .args(["-o", "stat=", "-p", &child_pid])
+            .output()?;
+        let state = String::from_utf8(state.stdout)?;
+        assert!(
+            state.trim().is_empty() || state.starts_with('Z'),
+            "The child of the process should have exited, but is in state {state}."
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_spawn_process_exits_despite_background_processes() -> Result<()> {
+        let (tx, mut rx) = unbounded_channel();
+        let mut command = Command::new("sh");
+        command.args(["-c", "sleep 30 & echo started"]);
+
+        let handle = spawn_process(ID, command, tx, CancellationToken::new())?;
+        timeout(Duration::from_secs(5), handle).await???;
+
+        assert_eq!(
+            rx.recv().await.map(|output| output.event),
+            Some(ProcessEvent::Stdout(String::from("started")))
+        );
+        assert_eq!(
+            rx.recv().await.map(|output| output.event),
+            Some(ProcessEvent::Exited(ExitStatus::Code(0)))
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_spawn_process_stops_reading_pipes_held_outside_its_group() -> Result<()> {
+        let (tx, mut rx) = unbounded_channel();
+        let mut command = Command::new("sh");
+        command.args(["-c", "setsid sleep 5 & echo started"]);
+
+        let handle = spawn_process(ID, command, tx, CancellationToken::new())?;
+        timeout(Duration::from_secs(4), handle).await???;
+
+        let mut last_event = None;
+        while let Ok(output) = rx.try_recv() {
+            last_event = Some(output.event);
+        }
+        assert_eq!(last_event, Some(ProcessEvent::Exited(ExitStatus::Code(0))));
+
+        Ok(())
+    }
+}
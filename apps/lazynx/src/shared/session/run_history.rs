@@ -0,0 +1,244 @@
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{self, Read, Seek, SeekFrom, Write},
+    os::fd::AsRawFd,
+    path::{Path, PathBuf},
+};
+
+use color_eyre::eyre::Result;
+use serde::{Deserialize, Serialize};
+
+use super::workspace_file_name;
+
+/// How many runs are kept before the oldest ones are dropped.
+const MAX_RECORDS: usize = 500;
+
+/// Where a run was started from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RunOrigin {
+    /// `lazynx run` in a shell.
+    Cli,
+    Tui,
+}
+
+/// A finished run of a target.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RunRecord {
+    pub project: String,
+    pub target: String,
+    #[serde(default)]
+    pub configuration: Option<String>,
+    /// Extra arguments passed to Nx.
+    #[serde(default)]
+    pub args: Vec<String>,
+    /// When the run started, in seconds since the Unix epoch.
+    pub started_at: i64,
+    pub duration_ms: u64,
+    /// `None` if the run was terminated by a signal.
+    pub exit_code: Option<i32>,
+    pub origin: RunOrigin,
+}
+
+impl RunRecord {
+    /// The task as Nx writes it, e.g. `api:build:production`.
+    pub fn task_name(&self) -> String {
+        return match &self.configuration {
+            Some(configuration) => format!("{}:{}:{configuration}", self.project, self.target),
+            None => format!("{}:{}", self.project, self.target),
+        };
+    }
+}
+
+/// The runs of a single workspace, from the TUI and the shell alike. They are
+/// stored one JSON object per line in `<data_dir>/history`, so both can append
+/// to the same file.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RunHistory {
+    file: PathBuf,
+}
+
+impl RunHistory {
+    pub fn new(data_dir: &Path, workspace_root: &Path) -> Self {
+        let file_name = workspace_file_name(workspace_root, "jsonl");
+
+        return RunHistory {
+            file: data_dir.join("history").join(file_name),
+        };
+    }
+
+    /// Every recorded run, oldest first. Lines that can't be read are skipped.
+    pub fn load(&self) -> Vec<RunRecord> {
+        let Ok(contents) = fs::read_to_string(&self.file) else {
+            return Vec::new();
+        };
+
+        return contents
+            .lines()
+            .filter_map(|line| serde_json::from_str(line).ok())
+            .collect();
+    }
+
+    /// The latest run of each task, newest first.
+    pub fn recent(&self, limit: usize) -> Vec<RunRecord> {
+        let mut recent: Vec<RunRecord> = Vec::new();
+
+        for record in self.load().into_iter().rev() {
+            if recent.len() == limit {
+                break;
+            }
+            if !recent.iter().any(|r| r.task_name() == record.task_name()) {
+                recent.push(record);
+            }
+        }
+
+        return recent;
+    }
+
+    /// Records the run, dropping the oldest ones past `MAX_RECORDS`.
+    ///
+    /// The TUI and `lazynx run` may append at the same time, so the file is
+    /// locked while it is written and trimmed.
+    pub fn append(&self, record: &RunRecord) -> Result<()> {
+        if let Some(dir) = self.file.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .read(true)
+            .open(&self.file)?;
+        lock_exclusive(&file)?;
+        writeln!(file, "{}", serde_json::to_string(record)?)?;
+
+        let mut contents = String::new();
+        file.seek(SeekFrom::Start(0))?;
+        file.read_to_string(&mut contents)?;
+
+        let lines: Vec<&str> = contents.lines().collect();
+        if lines.len() > MAX_RECORDS {
+            let kept = lines[lines.len() - MAX_RECORDS..].join("\n") + "\n";
+            // Appending to the emptied file writes from its start.
+            file.set_len(0)?;
+            file.write_all(kept.as_bytes())?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Blocks until the file is locked for this process alone. The lock is
+/// released once the file is closed.
+fn lock_exclusive(file: &File) -> io::Result<()> {
+    // SAFETY: `flock` has no memory safety requirements, and the descriptor
+    // stays open while `file` is borrowed.
+    let result = unsafe { libc::flock(file.as_raw_fd(), libc::LOCK_EX) };
+    if result != 0 {
+        return Err(io::Error::last_os_error());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod run_history_tests {
+    use super::{RunHistory, RunOrigin, RunRecord, MAX_RECORDS};
+
+    use std::{fs, path::Path, thread};
+
+    use color_eyre::eyre::Result;
+    use pretty_assertions::assert_eq;
+    use test_utils::TempDir;
+
+    fn setup(name: &str) -> Result<(RunHistory, TempDir)> {
+        let data_dir = TempDir::new(name)?;
+        let history = RunHistory::new(&data_dir.path, Path::new("/home/me/repo"));
+
+        return Ok((history, data_dir));
+    }
+
+    fn record(target: &str, started_at: i64) -> RunRecord {
+        return RunRecord {
+            project: String::from("api"),
+            target: target.to_owned(),
+            configuration: None,
+            args: Vec::new(),
+            started_at,
+            duration_ms: 1200,
+            exit_code: Some(0),
+            origin: RunOrigin::Cli,
+        };
+    }
+
+    #[test]
+    fn test_append_and_load() -> Result<()> {
+        let (history, _data_dir) = setup("run_history_append")?;
+        assert_eq!(history.load(), Vec::new());
+
+        history.append(&record("build", 1))?;
+        history.append(&record("test", 2))?;
+
+        assert_eq!(history.load(), vec![record("build", 1), record("test", 2)]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_recent_lists_each_task_once() -> Result<()> {
+        let (history, _data_dir) = setup("run_history_recent")?;
+        history.append(&record("build", 1))?;
+        history.append(&record("test", 2))?;
+        history.append(&record("build", 3))?;
+        fs::write(
+            &history.file,
+            fs::read_to_string(&history.file)? + "not json\n",
+        )?;
+
+        assert_eq!(
+            history.recent(5),
+            vec![record("build", 3), record("test", 2)]
+        );
+        assert_eq!(history.recent(1), vec![record("build", 3)]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_concurrent_appends_keep_every_record() -> Result<()> {
+        let (history, _data_dir) = setup("run_history_concurrent")?;
+        let writers = 8;
+        let runs_per_writer = MAX_RECORDS / writers + 20;
+
+        thread::scope(|scope| {
+            for writer in 0..writers {
+                let history = &history;
+                scope.spawn(move || {
+                    for run in 0..runs_per_writer {
+                        let started_at = (writer * runs_per_writer + run) as i64;
+                        history.append(&record("build", started_at)).unwrap();
+                    }
+                });
+            }
+        });
+
+        let records = history.load();
+        assert_eq!(records.len(), MAX_RECORDS, "Only the latest runs are kept.");
+        // A writer's runs are appended in order, so the ones kept have to be
+        // its latest, without any lost in between.
+        for writer in 0..writers {
+            let first_run = (writer * runs_per_writer) as i64;
+            let runs: Vec<i64> = records
+                .iter()
+                .map(|record| record.started_at - first_run)
+                .filter(|run| (0..runs_per_writer as i64).contains(run))
+                .collect();
+            let latest: Vec<i64> = (runs_per_writer - runs.len()..runs_per_writer)
+                .map(|run| run as i64)
+                .collect();
+            assert_eq!(runs, latest, "Runs of writer {writer} were lost.");
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,11 @@
+mod run_history;
+pub use run_history::{RunHistory, RunOrigin, RunRecord};
+
+mod session;
+pub use session::Session;
+
+mod session_store;
+pub use session_store::SessionStore;
+
+mod workspace_file_name;
+pub use workspace_file_name::workspace_file_name;
@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+
+use crate::shared::focus::Pane;
+
+/// Where the user left off in a workspace, restored on the next launch.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Session {
+    pub focused_pane: Option<Pane>,
+
+    /// How many lines the output is scrolled up from its end.
+    pub output_scroll: u16,
+
+    /// The text the projects list is filtered by.
+    pub project_filter: String,
+
+    pub selected_project: Option<String>,
+
+    /// The width of the sidebar, if it was resized.
+    pub sidebar_width: Option<u16>,
+}
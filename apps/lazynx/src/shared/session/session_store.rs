@@ -0,0 +1,104 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use color_eyre::eyre::Result;
+
+use super::{workspace_file_name, Session};
+
+/// Reads and writes the session of a single workspace. Every workspace gets
+/// its own file under `<data_dir>/sessions`, named after its root directory.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SessionStore {
+    file: PathBuf,
+}
+
+impl SessionStore {
+    pub fn new(data_dir: &Path, workspace_root: &Path) -> Self {
+        let file_name = workspace_file_name(workspace_root, "json");
+
+        return SessionStore {
+            file: data_dir.join("sessions").join(file_name),
+        };
+    }
+
+    /// The saved session, or an empty one when there is none yet or it can't
+    /// be read.
+    pub fn load(&self) -> Session {
+        let Ok(contents) = fs::read_to_string(&self.file) else {
+            return Session::default();
+        };
+
+        return serde_json::from_str(&contents).unwrap_or_else(|error| {
+            tracing::warn!("Ignoring the session in {}: {error}", self.file.display());
+            Session::default()
+        });
+    }
+
+    pub fn save(&self, session: &Session) -> Result<()> {
+        if let Some(dir) = self.file.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        fs::write(&self.file, serde_json::to_string_pretty(session)?)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod session_store_tests {
+    use super::SessionStore;
+
+    use std::{fs, path::Path};
+
+    use color_eyre::eyre::Result;
+    use pretty_assertions::assert_eq;
+    use test_utils::TempDir;
+
+    use crate::shared::{focus::Pane, session::Session};
+
+    fn setup(name: &str) -> Result<(SessionStore, TempDir)> {
+        let data_dir = TempDir::new(name)?;
+        let store = SessionStore::new(&data_dir.path, Path::new("/home/me/repo"));
+
+        return Ok((store, data_dir));
+    }
+
+    #[test]
+    fn test_file_is_named_after_workspace_root() {
+        let store = SessionStore::new(Path::new("/data"), Path::new("/home/me/repo"));
+
+        assert_eq!(store.file, Path::new("/data/sessions/home-me-repo.json"));
+    }
+
+    #[test]
+    fn test_save_and_load() -> Result<()> {
+        let (store, _data_dir) = setup("session_store_save")?;
+        assert_eq!(store.load(), Session::default());
+
+        let session = Session {
+            focused_pane: Some(Pane::Main),
+            project_filter: String::from("ui"),
+            selected_project: Some(String::from("shared-ui")),
+            ..Session::default()
+        };
+        store.save(&session)?;
+
+        assert_eq!(store.load(), session);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_session_is_ignored() -> Result<()> {
+        let (store, _data_dir) = setup("session_store_invalid")?;
+        store.save(&Session::default())?;
+        fs::write(&store.file, "not json")?;
+
+        assert_eq!(store.load(), Session::default());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,16 @@
+use std::path::{Component, Path};
+
+/// Names a file after the workspace's root directory, so each workspace gets
+/// its own, e.g. `home-me-repo.json` for `/home/me/repo`.
+pub fn workspace_file_name(workspace_root: &Path, extension: &str) -> String {
+    let name = workspace_root
+        .components()
+        .filter_map(|component| match component {
+            Component::Normal(part) => Some(part.to_string_lossy()),
+            _ => None,
+        })
+        .collect::<Vec<_>>()
+        .join("-");
+
+    return format!("{name}.{extension}");
+}
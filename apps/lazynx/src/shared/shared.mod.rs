@@ -1,6 +1,9 @@
 #[path = "./actions/actions.mod.rs"]
 pub mod actions;
 
+#[path = "./component/component.mod.rs"]
+pub mod component;
+
 #[path = "./config/config.mod.rs"]
 pub mod config;
 
@@ -10,8 +13,26 @@ pub mod errors;
 #[path = "./events/events.mod.rs"]
 pub mod events;
 
+#[path = "./focus/focus.mod.rs"]
+pub mod focus;
+
+#[path = "./keymap/keymap.mod.rs"]
+pub mod keymap;
+
 #[path = "./logger/logger.mod.rs"]
 pub mod logger;
 
+#[path = "./overlay/overlay.mod.rs"]
+pub mod overlay;
+
+#[path = "./process/process.mod.rs"]
+pub mod process;
+
+#[path = "./session/session.mod.rs"]
+pub mod session;
+
+#[path = "./theme/theme.mod.rs"]
+pub mod theme;
+
 #[path = "./tui/tui.mod.rs"]
 pub mod tui;
@@ -0,0 +1,2 @@
+mod theme;
+pub use theme::Theme;
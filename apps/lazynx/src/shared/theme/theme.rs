@@ -0,0 +1,45 @@
+use ratatui::style::{Color, Modifier, Style};
+use serde::{Deserialize, Serialize};
+
+/// The colors the UI is drawn with. Colors are given by name (e.g. `green`),
+/// as a hex code (e.g. `#00ff00`) or as an ANSI index (e.g. `42`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Theme {
+    /// The borders of the focused pane and of popups.
+    pub accent: Color,
+
+    /// The borders of error popups.
+    pub error: Color,
+
+    /// The characters matched by a search.
+    pub highlight: Color,
+
+    /// Secondary text, like key hints.
+    pub muted: Color,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        return Self {
+            accent: Color::Green,
+            error: Color::Red,
+            highlight: Color::Yellow,
+            muted: Color::DarkGray,
+        };
+    }
+}
+
+impl Theme {
+    /// Returns the border style for a pane, highlighting it when it is
+    /// focused.
+    pub fn border_style(&self, is_focused: bool) -> Style {
+        if !is_focused {
+            return Style::default();
+        }
+
+        return Style::default()
+            .fg(self.accent)
+            .add_modifier(Modifier::BOLD);
+    }
+}
@@ -56,6 +56,26 @@ impl TuiRunner {
         Ok(terminal)
     }
 
+    /// Turns mouse capture and bracketed paste on or off in the running
+    /// terminal.
+    pub fn update_input(&mut self, enable_mouse: bool, enable_paste: bool) -> Result<()> {
+        match (self.enable_mouse, enable_mouse) {
+            (false, true) => execute!(stdout(), EnableMouseCapture)?,
+            (true, false) => execute!(stdout(), DisableMouseCapture)?,
+            _ => {}
+        }
+        match (self.enable_paste, enable_paste) {
+            (false, true) => execute!(stdout(), EnableBracketedPaste)?,
+            (true, false) => execute!(stdout(), DisableBracketedPaste)?,
+            _ => {}
+        }
+
+        self.enable_mouse = enable_mouse;
+        self.enable_paste = enable_paste;
+
+        Ok(())
+    }
+
     pub fn restore(self) -> Result<()> {
         if self.enable_paste {
             execute!(stdout(), DisableBracketedPaste)?;
@@ -1,55 +1,545 @@
+use std::{collections::HashMap, env, rc::Rc, time::Instant};
+
+use chrono::Local;
 use color_eyre::eyre::Result;
 use ratatui::{
-    buffer::Buffer,
     layout::Rect,
     prelude::{Constraint, Direction, Layout},
-    widgets::Widget,
+    Frame,
 };
+use strum::IntoEnumIterator;
+use tokio::sync::mpsc::UnboundedSender;
 
 use super::sidebar_widget::SidebarWidget;
-use crate::workspace::WorkspaceViewWidget;
+use crate::{
+    doctor::DoctorReport,
+    shared::{
+        actions::Action,
+        component::Component,
+        config::{Config, CustomCommand},
+        events::Event,
+        focus::{FocusManager, Pane},
+        keymap::{format_key, KeyBinding, KeyContext, Keymap},
+        overlay::{
+            CommandPalette, ConfirmDialog, DoctorPopup, HelpPopup, HelpSection, Overlay,
+            PaletteEntry,
+        },
+        process::{ProcessEvent, ProcessHandler, ProcessId, ProcessOutput},
+        session::{RunHistory, RunOrigin, RunRecord, Session},
+    },
+    workspace::{
+        find_workspace_root, nx_run_command, nx_task_name, shell_command, WorkspaceViewWidget,
+    },
+};
 
-pub trait IAppWidget {
-    fn init(&mut self) -> Result<()>;
-}
+/// The narrowest either the sidebar or the main pane can be resized to.
+const MIN_PANE_WIDTH: u16 = 20;
+
+/// How many columns the sidebar grows or shrinks by at a time.
+const SIDEBAR_RESIZE_STEP: i32 = 2;
 
-#[derive(Clone, Default)]
+/// How many of the latest runs the command palette offers to rerun.
+const RECENT_RUNS_IN_PALETTE: usize = 5;
+
+#[derive(Default)]
 pub struct AppWidget {
+    action_tx: Option<UnboundedSender<Action>>,
+    area_width: u16,
+    commands: Vec<CustomCommand>,
+    /// The config the doctor checks the directories of.
+    config: Config,
+    favorite_targets: Vec<String>,
+    focus: FocusManager,
+    keymap: Keymap,
+    process_handler: Option<ProcessHandler>,
+    run_args: Vec<String>,
+    run_history: Option<RunHistory>,
+    /// The targets that are running, to record in the history once they exit.
+    running: HashMap<ProcessId, (RunRecord, Instant)>,
     sidebar: SidebarWidget,
+    /// The width the sidebar was resized to, if it was.
+    sidebar_width: Option<u16>,
     workspace: WorkspaceViewWidget,
 }
 
-impl IAppWidget for AppWidget {
-    fn init(&mut self) -> color_eyre::eyre::Result<()> {
-        self.sidebar.init()?;
+impl AppWidget {
+    /// Lists the bindings of the focused pane, followed by the global ones
+    /// its bindings don't shadow.
+    fn create_help(&self) -> Overlay {
+        let pane = KeyContext::from(self.focus.focused());
+        let pane_bindings = self.keymap.bindings_for(pane);
+        let global_bindings = self
+            .keymap
+            .bindings_for(KeyContext::Global)
+            .iter()
+            .filter(|global| {
+                !pane_bindings
+                    .iter()
+                    .any(|binding| KeyBinding::is_same_key(&binding.key, &global.key))
+            })
+            .cloned()
+            .collect();
+
+        let sections = [
+            (pane, pane_bindings.to_vec()),
+            (KeyContext::Global, global_bindings),
+        ]
+        .into_iter()
+        .map(|(context, bindings)| HelpSection {
+            bindings,
+            title: context.to_string(),
+        })
+        .filter(|section| !section.bindings.is_empty())
+        .collect();
+
+        return Overlay::Help(HelpPopup::new(sections, &self.keymap));
+    }
+
+    /// Runs the doctor's checks in the background, since they wait on `node`
+    /// and the package manager, then shows their report.
+    fn run_doctor(&self) -> Result<()> {
+        let Some(action_tx) = self.action_tx.clone() else {
+            return Ok(());
+        };
+        let config = self.config.clone();
+        let dir = env::current_dir()?;
+
+        tokio::task::spawn_blocking(move || {
+            // Asking the terminal about its keyboard would race the event loop
+            // for its input.
+            let report = DoctorReport::run(&config, &dir, false);
+            let popup = DoctorPopup::new(report.checks);
+            let _ = action_tx.send(Action::OpenOverlay(Overlay::Doctor(popup)));
+        });
+
         Ok(())
     }
-}
 
-impl Widget for AppWidget {
-    fn render(self, area: Rect, buf: &mut Buffer)
-    where
-        Self: Sized,
-    {
-        let chunks = Layout::default()
+    /// Lists every bound action with its keys, the favorite targets, recent
+    /// runs and custom commands, then every project and target.
+    fn create_palette(&self) -> Overlay {
+        let mut entries: Vec<PaletteEntry> = Vec::new();
+
+        let bindings = KeyContext::iter()
+            .flat_map(|context| self.keymap.bindings_for(context))
+            .filter(|binding| binding.action != Action::CommandPalette);
+
+        for binding in bindings {
+            let key = format_key(&binding.key);
+
+            match entries.iter_mut().find(|e| e.action == binding.action) {
+                Some(entry) => entry.key_hint = format!("{}, {key}", entry.key_hint),
+                None => entries.push(
+                    PaletteEntry::new(&binding.description(), binding.action.clone())
+                        .with_key_hint(&key),
+                ),
+            }
+        }
+
+        // The doctor has no key by default, but should still be found here.
+        if !entries.iter().any(|entry| entry.action == Action::Doctor) {
+            entries.push(PaletteEntry::new(
+                "Check the environment (doctor)",
+                Action::Doctor,
+            ));
+        }
+
+        let run_entries: Vec<(String, Action)> = self
+            .sidebar
+            .projects()
+            .iter()
+            .flat_map(|project| {
+                project.targets.keys().map(|target| {
+                    let action = Action::RunTarget {
+                        project: project.name.clone(),
+                        target: target.clone(),
+                        configuration: None,
+                    };
+                    (format!("{}:{target}", project.name), action)
+                })
+            })
+            .collect();
+
+        let is_favorite = |name: &String| self.favorite_targets.contains(name);
+        let (favorites, others): (Vec<_>, Vec<_>) = run_entries
+            .into_iter()
+            .partition(|(name, _)| is_favorite(name));
+
+        for (name, action) in favorites {
+            entries.push(PaletteEntry::new(&format!("★ Run {name}"), action));
+        }
+
+        let recent_runs = self
+            .run_history
+            .as_ref()
+            .map(|history| history.recent(RECENT_RUNS_IN_PALETTE))
+            .unwrap_or_default();
+
+        for record in recent_runs {
+            let outcome = match record.exit_code {
+                Some(0) => String::from("succeeded"),
+                Some(code) => format!("failed with {code}"),
+                None => String::from("terminated"),
+            };
+            let origin = match record.origin {
+                RunOrigin::Cli => " in the shell",
+                RunOrigin::Tui => "",
+            };
+            let action = Action::RunTarget {
+                project: record.project.clone(),
+                target: record.target.clone(),
+                configuration: record.configuration.clone(),
+            };
+            entries.push(PaletteEntry::new(
+                &format!("↻ Rerun {} ({outcome}{origin})", record.task_name()),
+                action,
+            ));
+        }
+
+        for command in &self.commands {
+            entries.push(PaletteEntry::new(
+                &format!("Command: {}", command.name),
+                Action::RunCommand(command.command.clone()),
+            ));
+        }
+
+        for project in self.sidebar.projects() {
+            entries.push(PaletteEntry::new(
+                &format!("Project: {}", project.name),
+                Action::SelectProject(project.name.clone()),
+            ));
+        }
+
+        for (name, action) in others {
+            entries.push(PaletteEntry::new(&format!("Run {name}"), action));
+        }
+
+        return Overlay::Palette(CommandPalette::new(entries));
+    }
+
+    /// Widens the sidebar by the number of columns, or narrows it if negative,
+    /// leaving both panes at least `MIN_PANE_WIDTH` wide.
+    fn resize_sidebar(&mut self, columns: i32) {
+        let width = self
+            .sidebar_width
+            .unwrap_or(self.create_layout(Rect::new(0, 0, self.area_width, 0))[0].width);
+        let max_width = self
+            .area_width
+            .saturating_sub(MIN_PANE_WIDTH)
+            .max(MIN_PANE_WIDTH);
+
+        let width = (i32::from(width) + columns).clamp(MIN_PANE_WIDTH.into(), max_width.into());
+        self.sidebar_width = Some(width as u16);
+    }
+
+    fn create_layout(&self, area: Rect) -> Rc<[Rect]> {
+        let constraints = match self.sidebar_width {
+            Some(width) => vec![Constraint::Length(width), Constraint::Fill(1)],
+            None => vec![Constraint::Min(38), Constraint::Percentage(75)],
+        };
+
+        return Layout::default()
             .direction(Direction::Horizontal)
-            .constraints(vec![Constraint::Min(38), Constraint::Percentage(75)])
+            .constraints(constraints)
             .split(area);
+    }
+
+    fn run_target(
+        &mut self,
+        project: &str,
+        target: &str,
+        configuration: Option<&str>,
+    ) -> Result<()> {
+        let Some(process_handler) = &self.process_handler else {
+            return Ok(());
+        };
+
+        let id = process_handler.spawn(nx_run_command(
+            project,
+            target,
+            configuration,
+            &self.run_args,
+        ))?;
+
+        let record = RunRecord {
+            project: project.to_owned(),
+            target: target.to_owned(),
+            configuration: configuration.map(str::to_owned),
+            args: Vec::new(),
+            started_at: Local::now().timestamp(),
+            duration_ms: 0,
+            exit_code: None,
+            origin: RunOrigin::Tui,
+        };
+        self.running.insert(id, (record, Instant::now()));
+
+        Ok(())
+    }
+
+    /// Asks before running a target that is still running, since two dev
+    /// servers would fight over the same port.
+    fn confirm_rerun(&self, action: &Action) -> Option<ConfirmDialog> {
+        let Action::RunTarget {
+            project,
+            target,
+            configuration,
+        } = action
+        else {
+            return None;
+        };
+
+        let task = nx_task_name(project, target, configuration.as_deref());
+        if !self
+            .running
+            .values()
+            .any(|(record, _)| record.task_name() == task)
+        {
+            return None;
+        }
+
+        return Some(ConfirmDialog::new(
+            "Run Again",
+            &format!("{task} is still running. Run it again?"),
+            Action::Confirmed(Box::new(action.clone())),
+        ));
+    }
+
+    /// Records the target the process ran in the history once it exits.
+    fn finish_run(&mut self, id: ProcessId, exit_code: Option<i32>) {
+        let Some((mut record, started)) = self.running.remove(&id) else {
+            return;
+        };
+        let Some(run_history) = &self.run_history else {
+            return;
+        };
+
+        record.duration_ms = started.elapsed().as_millis() as u64;
+        record.exit_code = exit_code;
+
+        if let Err(error) = run_history.append(&record) {
+            tracing::error!(
+                "Unable to record the run of {}: {error}",
+                record.task_name()
+            );
+        }
+    }
+
+    /// Selects the project and runs the target, then focuses the pane, or
+    /// else wherever selecting and running would have.
+    fn open_link(
+        &mut self,
+        project: Option<String>,
+        pane: Option<Pane>,
+        target: Option<String>,
+        configuration: Option<String>,
+    ) -> Result<Option<Action>> {
+        let Some(project) = project else {
+            return Ok(pane.map(Action::Focus));
+        };
+
+        let mut follow_up = self.update(Action::SelectProject(project.clone()))?;
+        if !self.sidebar.projects().iter().any(|p| p.name == project) {
+            return Ok(Some(Action::Error(format!(
+                "There is no project named `{project}` in the workspace."
+            ))));
+        }
+
+        if let Some(target) = target {
+            follow_up = self.update(Action::RunTarget {
+                project,
+                target,
+                configuration,
+            })?;
+        }
+
+        return Ok(match (follow_up, pane) {
+            (Some(Action::Focus(_)), Some(pane)) => Some(Action::Focus(pane)),
+            (follow_up, _) => follow_up,
+        });
+    }
+
+    /// Runs a custom command at the root of the workspace.
+    fn run_command(&self, command: &str) -> Result<()> {
+        let Some(process_handler) = &self.process_handler else {
+            return Ok(());
+        };
+
+        let current_dir = env::current_dir()?;
+        let dir = find_workspace_root(&current_dir).unwrap_or(current_dir);
+
+        process_handler.spawn(shell_command(command, &dir))?;
+
+        Ok(())
+    }
+}
+
+impl Component for AppWidget {
+    fn register_action_handler(&mut self, tx: UnboundedSender<Action>) -> Result<()> {
+        self.action_tx = Some(tx);
+        Ok(())
+    }
+
+    fn register_process_handler(&mut self, handler: ProcessHandler) -> Result<()> {
+        self.process_handler = Some(handler);
+        Ok(())
+    }
+
+    fn register_config_handler(&mut self, config: Config) -> Result<()> {
+        self.commands = config.commands.clone();
+        self.config = config.clone();
+        self.favorite_targets = config.favorite_targets.clone();
+        self.keymap = config.keymap.clone();
+        self.run_args = config.run_args.clone();
+        self.run_history = find_workspace_root(&env::current_dir()?)
+            .map(|root| RunHistory::new(&config.data_dir, &root));
+        self.sidebar.register_config_handler(config.clone())?;
+        self.workspace.register_config_handler(config)
+    }
+
+    fn init(&mut self) -> Result<()> {
+        self.sidebar.init()?;
+        self.workspace.init()?;
+        self.update(Action::Focus(self.focus.focused()))?;
+        Ok(())
+    }
+
+    fn restore_session(&mut self, session: &Session) -> Result<()> {
+        self.sidebar_width = session.sidebar_width;
+        self.sidebar.restore_session(session)?;
+        self.workspace.restore_session(session)?;
+
+        if let Some(pane) = session.focused_pane {
+            self.update(Action::Focus(pane))?;
+        }
+
+        Ok(())
+    }
+
+    fn save_session(&self, session: &mut Session) {
+        session.focused_pane = Some(self.focus.focused());
+        session.sidebar_width = self.sidebar_width;
+        self.sidebar.save_session(session);
+        self.workspace.save_session(session);
+    }
+
+    fn key_context(&self) -> Option<KeyContext> {
+        return Some(KeyContext::from(self.focus.focused()));
+    }
+
+    fn handle_events(&mut self, event: Event) -> Option<Action> {
+        return match self.focus.focused() {
+            Pane::Main => self.workspace.handle_events(event),
+            _ => self.sidebar.handle_events(event),
+        };
+    }
+
+    fn update(&mut self, action: Action) -> Result<Option<Action>> {
+        let action = match action {
+            Action::Confirmed(action) => *action,
+            action => match self.confirm_rerun(&action) {
+                Some(dialog) => return Ok(Some(Action::OpenOverlay(Overlay::Confirm(dialog)))),
+                None => action,
+            },
+        };
+
+        match action {
+            Action::FocusNext => return Ok(Some(Action::Focus(self.focus.next()))),
+            Action::FocusPrev => return Ok(Some(Action::Focus(self.focus.prev()))),
+            Action::Help => return Ok(Some(Action::OpenOverlay(self.create_help()))),
+            Action::Doctor => {
+                return Ok(self.run_doctor().err().map(|error| {
+                    Action::Error(format!("Unable to check the environment: {error}"))
+                }))
+            }
+            Action::CommandPalette => return Ok(Some(Action::OpenOverlay(self.create_palette()))),
+            Action::GrowSidebar => self.resize_sidebar(SIDEBAR_RESIZE_STEP),
+            Action::ShrinkSidebar => self.resize_sidebar(-SIDEBAR_RESIZE_STEP),
+            Action::Focus(pane) if !self.focus.focus(pane) => return Ok(None),
+            Action::OpenLink {
+                project,
+                pane,
+                target,
+                configuration,
+            } => return self.open_link(project, pane, target, configuration),
+            _ => {}
+        }
+
+        let follow_up = match &action {
+            Action::SelectProject(_) => Some(Action::Focus(Pane::Projects)),
+            Action::RunCommand(command) => match self.run_command(command) {
+                Ok(()) => Some(Action::Focus(Pane::Main)),
+                Err(error) => Some(Action::Error(format!("Unable to run `{command}`: {error}"))),
+            },
+            Action::RunTarget {
+                project,
+                target,
+                configuration,
+            } => match self.run_target(project, target, configuration.as_deref()) {
+                Ok(()) => Some(Action::Focus(Pane::Main)),
+                Err(error) => Some(Action::Error(format!(
+                    "Unable to run {}: {error}",
+                    nx_task_name(project, target, configuration.as_deref())
+                ))),
+            },
+            Action::ProcessOutput(ProcessOutput {
+                id,
+                event: ProcessEvent::Exited(status),
+            }) => {
+                self.finish_run(*id, status.code());
+                None
+            }
+            _ => None,
+        };
+
+        let sidebar_follow_up = self.sidebar.update(action.clone())?;
+        self.workspace.update(action)?;
+
+        Ok(follow_up.or(sidebar_follow_up))
+    }
+
+    fn draw(&mut self, frame: &mut Frame, area: Rect) {
+        self.area_width = area.width;
+        let chunks = self.create_layout(area);
 
-        self.sidebar.render(chunks[0], buf);
-        self.workspace.render(chunks[1], buf);
+        self.sidebar.draw(frame, chunks[0]);
+        self.workspace.draw(frame, chunks[1]);
     }
 }
 
 #[cfg(test)]
 mod app_widget_tests {
-    use super::{AppWidget, IAppWidget};
+    use super::AppWidget;
+
+    use std::{collections::BTreeMap, path::Path, time::Instant};
 
     use color_eyre::eyre::Result;
+    use crossterm::event::{Event as CrosstermEvent, KeyCode, KeyEvent, KeyModifiers};
     use insta::assert_snapshot;
+    use pretty_assertions::assert_eq;
+    use test_case::test_case;
+    use tokio::{
+        sync::mpsc::unbounded_channel,
+        time::{timeout, Duration},
+    };
 
-    use crate::workspace::{test_bed::WorkspaceTestBed, WorkspaceViewWidget};
-    use test_utils::{mocks::MOCK_DATE, WidgetTestBed};
+    use crate::{
+        shared::{
+            actions::Action,
+            component::Component,
+            config::{Config, CustomCommand},
+            events::Event,
+            focus::Pane,
+            keymap::{KeyContext, Keymap},
+            overlay::{Overlay, OverlayOutcome},
+            process::{ExitStatus, ProcessEvent, ProcessId, ProcessOutput},
+            session::{RunHistory, RunOrigin, RunRecord, Session},
+        },
+        workspace::{
+            test_bed::{MockNxWorkspace, WorkspaceTestBed},
+            WorkspaceViewWidget,
+        },
+    };
+    use test_utils::{mocks::MOCK_DATE, TempDir, WidgetTestBed};
 
     struct TestBed {
         widget: WidgetTestBed<AppWidget>,
@@ -58,8 +548,10 @@ mod app_widget_tests {
 
     impl Default for TestBed {
         fn default() -> Self {
-            let mut widget = AppWidget::default();
-            widget.workspace = WorkspaceViewWidget::new(*MOCK_DATE);
+            let widget = AppWidget {
+                workspace: WorkspaceViewWidget::new(*MOCK_DATE),
+                ..Default::default()
+            };
             let widget_testbed = WidgetTestBed::<AppWidget>::new(100, 50).with_widget(widget);
 
             return TestBed {
@@ -69,16 +561,400 @@ mod app_widget_tests {
         }
     }
 
+    fn key_event(code: KeyCode) -> Event {
+        return Event::Crossterm(CrosstermEvent::Key(KeyEvent::new(
+            code,
+            KeyModifiers::empty(),
+        )));
+    }
+
+    #[test_case(Action::FocusNext, Pane::Targets; "Next")]
+    #[test_case(Action::FocusPrev, Pane::Workspace; "Previous")]
+    fn test_focus_cycle(action: Action, expected: Pane) -> Result<()> {
+        let mut widget = AppWidget::default();
+
+        let response = widget.update(action)?;
+
+        assert_eq!(response, Some(Action::Focus(expected)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_focus_is_forwarded_to_panes() -> Result<()> {
+        let mut widget = AppWidget::default();
+
+        widget.update(Action::Focus(Pane::Main))?;
+
+        assert_eq!(widget.focus.focused(), Pane::Main);
+        assert_eq!(
+            widget.handle_events(key_event(KeyCode::Char('j'))),
+            None,
+            "Keys should only reach the focused pane."
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_help_lists_focused_pane_bindings() -> Result<()> {
+        let mut widget = AppWidget::default();
+        widget.update(Action::Focus(Pane::Workspace))?;
+
+        let Some(Action::OpenOverlay(Overlay::Help(help))) = widget.update(Action::Help)? else {
+            panic!("Help should have been opened.");
+        };
+        let titles: Vec<&str> = help.sections.iter().map(|s| s.title.as_str()).collect();
+        assert_eq!(
+            titles,
+            vec!["Global"],
+            "The workspace pane has no bindings."
+        );
+
+        widget.update(Action::Focus(Pane::Projects))?;
+
+        let Some(Action::OpenOverlay(Overlay::Help(help))) = widget.update(Action::Help)? else {
+            panic!("Help should have been opened.");
+        };
+        let titles: Vec<&str> = help.sections.iter().map(|s| s.title.as_str()).collect();
+        assert_eq!(titles, vec!["Projects", "Global"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_help_leaves_out_shadowed_global_bindings() -> Result<()> {
+        let keybindings = BTreeMap::from([(
+            KeyContext::Projects,
+            BTreeMap::from([(String::from("?"), String::from("FilterProjects"))]),
+        )]);
+        let mut widget = AppWidget {
+            keymap: Keymap::default().with_keybindings(&keybindings)?,
+            ..Default::default()
+        };
+        widget.update(Action::Focus(Pane::Projects))?;
+
+        let Some(Action::OpenOverlay(Overlay::Help(help))) = widget.update(Action::Help)? else {
+            panic!("Help should have been opened.");
+        };
+        let actions_of = |title: &str| -> Vec<Action> {
+            return help
+                .sections
+                .iter()
+                .filter(|section| section.title == title)
+                .flat_map(|section| &section.bindings)
+                .filter(|binding| binding.key.code == KeyCode::Char('?'))
+                .map(|binding| binding.action.clone())
+                .collect();
+        };
+        assert_eq!(actions_of("Projects"), vec![Action::FilterProjects]);
+        assert_eq!(
+            actions_of("Global"),
+            Vec::<Action>::new(),
+            "The pane's `?` shadows the global one."
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_palette_lists_actions_projects_and_targets() -> Result<()> {
+        let workspace = MockNxWorkspace::new("app_widget_palette")?;
+        let mut widget = AppWidget::default();
+        widget.sidebar.projects_tab.load_projects(&workspace.root);
+
+        let Some(Action::OpenOverlay(Overlay::Palette(palette))) =
+            widget.update(Action::CommandPalette)?
+        else {
+            panic!("The command palette should have been opened.");
+        };
+        let find = |label: &str| palette.entries.iter().find(|entry| entry.label == label);
+
+        assert_eq!(
+            find("Quit").map(|entry| entry.key_hint.as_str()),
+            Some("Ctrl+c, Ctrl+d")
+        );
+        assert_eq!(
+            find("Project: api").map(|entry| &entry.action),
+            Some(&Action::SelectProject(String::from("api")))
+        );
+        assert_eq!(
+            find("Run shared-ui:lint").map(|entry| &entry.action),
+            Some(&Action::RunTarget {
+                project: String::from("shared-ui"),
+                target: String::from("lint"),
+                configuration: None,
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_palette_lists_favorites_and_commands_first() -> Result<()> {
+        let workspace = MockNxWorkspace::new("app_widget_palette_favorites")?;
+        let mut widget = AppWidget::default();
+        widget.register_config_handler(Config {
+            commands: vec![CustomCommand {
+                name: String::from("Lint everything"),
+                command: String::from("npx nx run-many -t lint"),
+            }],
+            data_dir: workspace.root.join("tmp"),
+            favorite_targets: vec![String::from("shared-ui:lint")],
+            ..Config::default()
+        })?;
+        widget.sidebar.projects_tab.load_projects(&workspace.root);
+
+        let Some(Action::OpenOverlay(Overlay::Palette(palette))) =
+            widget.update(Action::CommandPalette)?
+        else {
+            panic!("The command palette should have been opened.");
+        };
+        let position = |label: &str| palette.entries.iter().position(|e| e.label == label);
+
+        let favorite = position("★ Run shared-ui:lint").expect("The favorite should be listed.");
+        let command = position("Command: Lint everything").expect("The command should be listed.");
+        let project = position("Project: api").expect("The project should be listed.");
+
+        assert!(favorite < command && command < project);
+        assert_eq!(
+            position("Run shared-ui:lint"),
+            None,
+            "Favorites should not be listed twice."
+        );
+        assert_eq!(
+            palette.entries[command].action,
+            Action::RunCommand(String::from("npx nx run-many -t lint"))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_select_project_focuses_projects_pane() -> Result<()> {
+        let mut widget = AppWidget::default();
+
+        let response = widget.update(Action::SelectProject(String::from("api")))?;
+
+        assert_eq!(response, Some(Action::Focus(Pane::Projects)));
+
+        Ok(())
+    }
+
+    #[test_case(Some("api"), None, None, Some(Action::Focus(Pane::Projects)); "Project")]
+    #[test_case(Some("api"), Some(Pane::Main), None, Some(Action::Focus(Pane::Main)); "Project And Pane")]
+    #[test_case(Some("api"), None, Some("build"), Some(Action::Focus(Pane::Main)); "Target")]
+    #[test_case(Some("api"), Some(Pane::Workspace), Some("build"), Some(Action::Focus(Pane::Workspace)); "Target And Pane")]
+    #[test_case(Some("api"), Some(Pane::Targets), None, Some(Action::Focus(Pane::Targets)); "Project Targets")]
+    #[test_case(None, Some(Pane::Main), None, Some(Action::Focus(Pane::Main)); "Pane Only")]
+    #[test_case(Some("web"), None, None, Some(Action::Error(String::from("There is no project named `web` in the workspace."))); "Unknown Project")]
+    fn test_open_link(
+        project: Option<&str>,
+        pane: Option<Pane>,
+        target: Option<&str>,
+        expected: Option<Action>,
+    ) -> Result<()> {
+        let workspace =
+            MockNxWorkspace::new(&format!("app_widget_link_{project:?}_{pane:?}_{target:?}"))?;
+        let mut widget = AppWidget::default();
+        widget.sidebar.projects_tab.load_projects(&workspace.root);
+        widget.update(Action::SelectProject(String::from("shared-ui")))?;
+
+        let response = widget.update(Action::OpenLink {
+            project: project.map(str::to_owned),
+            pane,
+            target: target.map(str::to_owned),
+            configuration: None,
+        })?;
+
+        assert_eq!(response, expected);
+        if project == Some("api") {
+            let mut session = Session::default();
+            widget.save_session(&mut session);
+            assert_eq!(session.selected_project.as_deref(), Some("api"));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_run_selected_target() -> Result<()> {
+        let workspace = MockNxWorkspace::new("app_widget_run_selected")?;
+        let mut widget = AppWidget::default();
+        widget.sidebar.projects_tab.load_projects(&workspace.root);
+        widget.update(Action::SelectProject(String::from("shared-ui")))?;
+
+        let response = widget.update(Action::RunSelectedTarget)?;
+
+        assert_eq!(
+            response,
+            Some(Action::RunTarget {
+                project: String::from("shared-ui"),
+                target: String::from("lint"),
+                configuration: None,
+            })
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_resize_sidebar() -> Result<()> {
+        let mut widget = AppWidget {
+            area_width: 100,
+            ..AppWidget::default()
+        };
+
+        widget.update(Action::GrowSidebar)?;
+        assert_eq!(widget.sidebar_width, Some(40));
+
+        for _ in 0..50 {
+            widget.update(Action::ShrinkSidebar)?;
+        }
+        assert_eq!(
+            widget.sidebar_width,
+            Some(20),
+            "The sidebar has a minimum width."
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_doctor_reports_in_the_background() -> Result<()> {
+        let (tx, mut rx) = unbounded_channel();
+        let mut widget = AppWidget::default();
+        widget.register_action_handler(tx)?;
+
+        let follow_up = widget.update(Action::Doctor)?;
+        assert_eq!(follow_up, None, "The checks should not block the update.");
+
+        let report = timeout(Duration::from_secs(10), rx.recv()).await?;
+        assert!(
+            matches!(report, Some(Action::OpenOverlay(Overlay::Doctor(_)))),
+            "The report should have been sent, not {report:?}."
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_concurrent_runs_are_recorded_separately() -> Result<()> {
+        let data_dir = TempDir::new("app_widget_concurrent_runs")?;
+        let run_history = RunHistory::new(&data_dir.path, Path::new("/workspace"));
+        let mut widget = AppWidget {
+            run_history: Some(run_history.clone()),
+            ..AppWidget::default()
+        };
+        let record = |target: &str| RunRecord {
+            project: String::from("api"),
+            target: target.to_owned(),
+            configuration: None,
+            args: Vec::new(),
+            started_at: 0,
+            duration_ms: 0,
+            exit_code: None,
+            origin: RunOrigin::Tui,
+        };
+        widget
+            .running
+            .insert(ProcessId(0), (record("build"), Instant::now()));
+        widget
+            .running
+            .insert(ProcessId(1), (record("test"), Instant::now()));
+
+        widget.update(Action::ProcessOutput(ProcessOutput {
+            id: ProcessId(1),
+            event: ProcessEvent::Exited(ExitStatus::Code(2)),
+        }))?;
+
+        let recorded = run_history.load();
+        assert_eq!(recorded.len(), 1);
+        assert_eq!(recorded[0].target, "test");
+        assert_eq!(recorded[0].exit_code, Some(2));
+        assert!(
+            widget.running.contains_key(&ProcessId(0)),
+            "The build should still be running."
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_running_a_running_target_asks_first() -> Result<()> {
+        let mut widget = AppWidget::default();
+        let run_build = Action::RunTarget {
+            project: String::from("api"),
+            target: String::from("build"),
+            configuration: None,
+        };
+        let record = RunRecord {
+            project: String::from("api"),
+            target: String::from("build"),
+            configuration: None,
+            args: Vec::new(),
+            started_at: 0,
+            duration_ms: 0,
+            exit_code: None,
+            origin: RunOrigin::Tui,
+        };
+        widget
+            .running
+            .insert(ProcessId(0), (record, Instant::now()));
+
+        let Some(Action::OpenOverlay(Overlay::Confirm(mut dialog))) =
+            widget.update(run_build.clone())?
+        else {
+            panic!("Running api:build again should have been confirmed first.");
+        };
+        let OverlayOutcome::Close(Some(confirmed)) =
+            dialog.handle_key(KeyEvent::new(KeyCode::Char('y'), KeyModifiers::empty()))
+        else {
+            panic!("Confirming should have run api:build.");
+        };
+
+        assert_eq!(confirmed, Action::Confirmed(Box::new(run_build)));
+        assert_eq!(widget.update(confirmed)?, Some(Action::Focus(Pane::Main)));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_save_and_restore_session() -> Result<()> {
+        let workspace = MockNxWorkspace::new("app_widget_session")?;
+        let mut widget = AppWidget::default();
+        widget.sidebar.projects_tab.load_projects(&workspace.root);
+
+        let session = Session {
+            focused_pane: Some(Pane::Main),
+            output_scroll: 0,
+            project_filter: String::from("ui"),
+            selected_project: Some(String::from("shared-ui")),
+            sidebar_width: Some(42),
+        };
+        widget.restore_session(&session)?;
+
+        assert_eq!(widget.focus.focused(), Pane::Main);
+
+        let mut saved = Session::default();
+        widget.save_session(&mut saved);
+
+        assert_eq!(saved, session);
+
+        Ok(())
+    }
+
     #[test]
     fn test_app_widget_render() -> Result<()> {
         let mut test_bed = TestBed::default();
         test_bed.workspace.setup();
 
-        test_bed.widget.widget.init().unwrap();
+        let widget = &mut test_bed.widget.widget;
+        widget.init().unwrap();
         test_bed
             .widget
             .terminal
-            .draw(|f| f.render_widget(test_bed.widget.widget, f.area()))
+            .draw(|f| widget.draw(f, f.area()))
             .unwrap();
 
         assert_snapshot!(test_bed.widget.terminal.backend());
@@ -1,4 +1,4 @@
 mod sidebar_widget;
 
 mod app_widget;
-pub use app_widget::{AppWidget, IAppWidget};
+pub use app_widget::AppWidget;
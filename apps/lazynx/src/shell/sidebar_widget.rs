@@ -1,45 +1,109 @@
 use std::rc::Rc;
 
+use color_eyre::eyre::Result;
 use ratatui::{
-    buffer::Buffer,
     prelude::{Constraint, Direction, Layout, Rect},
-    widgets::Widget,
+    Frame,
 };
 
-use crate::workspace::WorkspaceTabWidget;
+use crate::{
+    projects::{ProjectsTabWidget, TargetsTabWidget},
+    shared::{
+        actions::Action, component::Component, config::Config, events::Event, focus::Pane,
+        session::Session,
+    },
+    workspace::{Project, WorkspaceTabWidget},
+};
 
-#[derive(Default, Clone)]
+#[derive(Default)]
 pub struct SidebarWidget {
+    focused: Option<Pane>,
+    pub(super) projects_tab: ProjectsTabWidget,
+    targets_tab: TargetsTabWidget,
     workspace_tab: WorkspaceTabWidget,
 }
 
 impl SidebarWidget {
-    pub fn init(&mut self) -> color_eyre::eyre::Result<()> {
-        self.workspace_tab.init()?;
-        Ok(())
+    pub fn projects(&self) -> &[Project] {
+        return self.projects_tab.projects();
+    }
+
+    /// Lists the targets of the project selected in the projects tab.
+    fn show_selected_targets(&mut self) {
+        self.targets_tab
+            .show_project(self.projects_tab.selected_project());
     }
 
     fn create_layout(&self, area: Rect) -> Rc<[Rect]> {
         return Layout::default()
             .direction(Direction::Vertical)
-            .constraints(vec![Constraint::Length(3), Constraint::Fill(1)])
+            .constraints(vec![
+                Constraint::Length(3),
+                Constraint::Fill(2),
+                Constraint::Fill(1),
+            ])
             .split(area);
     }
 }
 
-impl Widget for SidebarWidget {
-    fn render(self, area: Rect, buf: &mut Buffer)
-    where
-        Self: Sized,
-    {
+impl Component for SidebarWidget {
+    fn register_config_handler(&mut self, config: Config) -> Result<()> {
+        self.workspace_tab.register_config_handler(config.clone())?;
+        self.projects_tab.register_config_handler(config.clone())?;
+        self.targets_tab.register_config_handler(config)
+    }
+
+    fn init(&mut self) -> Result<()> {
+        self.workspace_tab.init()?;
+        self.projects_tab.init()?;
+        self.show_selected_targets();
+        Ok(())
+    }
+
+    fn restore_session(&mut self, session: &Session) -> Result<()> {
+        self.projects_tab.restore_session(session)?;
+        self.show_selected_targets();
+        Ok(())
+    }
+
+    fn save_session(&self, session: &mut Session) {
+        self.projects_tab.save_session(session);
+    }
+
+    fn handle_events(&mut self, event: Event) -> Option<Action> {
+        let action = match self.focused {
+            Some(Pane::Workspace) => self.workspace_tab.handle_events(event),
+            Some(Pane::Projects) => self.projects_tab.handle_events(event),
+            Some(Pane::Targets) => self.targets_tab.handle_events(event),
+            _ => None,
+        };
+        self.show_selected_targets();
+
+        return action;
+    }
+
+    fn update(&mut self, action: Action) -> Result<Option<Action>> {
+        if let Action::Focus(pane) = action {
+            self.focused = Some(pane);
+        }
+
+        self.workspace_tab.update(action.clone())?;
+        self.projects_tab.update(action.clone())?;
+        self.show_selected_targets();
+        self.targets_tab.update(action)
+    }
+
+    fn draw(&mut self, frame: &mut Frame, area: Rect) {
         let chunks = self.create_layout(area);
-        self.workspace_tab.render(chunks[0], buf);
+        self.workspace_tab.draw(frame, chunks[0]);
+        self.projects_tab.draw(frame, chunks[1]);
+        self.targets_tab.draw(frame, chunks[2]);
     }
 }
 
 #[cfg(test)]
 mod sidebar_widget_tests {
-    use crate::workspace::test_bed::WorkspaceTestBed;
+    use crate::{shared::component::Component, workspace::test_bed::WorkspaceTestBed};
 
     use super::SidebarWidget;
 
@@ -67,11 +131,12 @@ mod sidebar_widget_tests {
         let mut test_bed = TestBed::default();
         test_bed.workspace.setup();
 
-        test_bed.widget.widget.init().unwrap();
+        let widget = &mut test_bed.widget.widget;
+        widget.init().unwrap();
         test_bed
             .widget
             .terminal
-            .draw(|f| f.render_widget(test_bed.widget.widget, f.area()))
+            .draw(|f| widget.draw(f, f.area()))
             .unwrap();
 
         assert_snapshot!(test_bed.widget.terminal.backend());
@@ -0,0 +1,56 @@
+use tokio::process::Command;
+
+/// The task as Nx writes it, e.g. `api:build:production`.
+pub fn nx_task_name(project: &str, target: &str, configuration: Option<&str>) -> String {
+    return match configuration {
+        Some(configuration) => format!("{project}:{target}:{configuration}"),
+        None => format!("{project}:{target}"),
+    };
+}
+
+/// Builds the command running a project's target, in the given configuration
+/// if any, through the workspace's own copy of Nx, passing along any extra
+/// arguments.
+pub fn nx_run_command(
+    project: &str,
+    target: &str,
+    configuration: Option<&str>,
+    args: &[String],
+) -> Command {
+    let mut command = Command::new("npx");
+    command.args(["nx", "run", &nx_task_name(project, target, configuration)]);
+    command.args(args);
+
+    return command;
+}
+
+#[cfg(test)]
+mod nx_command_tests {
+    use super::nx_run_command;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_nx_run_command() {
+        let command = nx_run_command("api", "build", None, &[]);
+        let command = command.as_std();
+
+        assert_eq!(command.get_program(), "npx");
+        assert_eq!(
+            command.get_args().collect::<Vec<_>>(),
+            vec!["nx", "run", "api:build"]
+        );
+    }
+
+    #[test]
+    fn test_nx_run_command_with_configuration_and_args() {
+        let args = vec![String::from("--skip-nx-cache")];
+
+        let command = nx_run_command("api", "build", Some("production"), &args);
+
+        assert_eq!(
+            command.as_std().get_args().collect::<Vec<_>>(),
+            vec!["nx", "run", "api:build:production", "--skip-nx-cache"]
+        );
+    }
+}
@@ -0,0 +1,60 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use serde_json::Value;
+
+/// The version of Nx installed in the workspace's `node_modules`, if any.
+pub fn installed_nx_version(root: &Path) -> Option<String> {
+    return read_json(root.join("node_modules/nx/package.json"))
+        .and_then(|package| package["version"].as_str().map(str::to_owned));
+}
+
+/// The version of Nx installed in the workspace, or else the one its
+/// `package.json` asks for.
+pub fn find_nx_version(root: &Path) -> Option<String> {
+    if let Some(installed) = installed_nx_version(root) {
+        return Some(installed);
+    }
+
+    let package = read_json(root.join("package.json"))?;
+    return ["devDependencies", "dependencies"]
+        .iter()
+        .find_map(|dependencies| package[dependencies]["nx"].as_str())
+        .map(str::to_owned);
+}
+
+fn read_json(path: PathBuf) -> Option<Value> {
+    return serde_json::from_str(&fs::read_to_string(path).ok()?).ok();
+}
+
+#[cfg(test)]
+mod nx_version_tests {
+    use super::{find_nx_version, installed_nx_version};
+
+    use color_eyre::eyre::Result;
+    use pretty_assertions::assert_eq;
+
+    use crate::workspace::test_bed::MockNxWorkspace;
+
+    #[test]
+    fn test_installed_version_wins() -> Result<()> {
+        let workspace = MockNxWorkspace::new("nx_version_installed")?;
+        workspace.write(
+            "package.json",
+            r#"{ "devDependencies": { "nx": "^20.0.0" } }"#,
+        )?;
+        assert_eq!(installed_nx_version(&workspace.root), None);
+        assert_eq!(find_nx_version(&workspace.root).as_deref(), Some("^20.0.0"));
+
+        workspace.write("node_modules/nx/package.json", r#"{ "version": "20.6.2" }"#)?;
+        assert_eq!(
+            installed_nx_version(&workspace.root).as_deref(),
+            Some("20.6.2")
+        );
+        assert_eq!(find_nx_version(&workspace.root).as_deref(), Some("20.6.2"));
+
+        Ok(())
+    }
+}
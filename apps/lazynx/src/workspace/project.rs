@@ -0,0 +1,55 @@
+use std::{collections::BTreeMap, path::PathBuf};
+
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// An Nx project, as described by its `project.json`.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Project {
+    #[serde(default)]
+    pub name: String,
+
+    /// The project's directory, relative to the workspace root.
+    #[serde(default)]
+    pub root: PathBuf,
+
+    #[serde(default)]
+    pub project_type: Option<ProjectType>,
+
+    #[serde(default)]
+    pub tags: Vec<String>,
+
+    #[serde(default)]
+    pub targets: BTreeMap<String, Target>,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum ProjectType {
+    #[value(name = "app", alias = "application")]
+    Application,
+    #[value(name = "lib", alias = "library")]
+    Library,
+}
+
+impl ProjectType {
+    /// The short name Nx users know the type by, e.g. `app`.
+    pub fn short_name(self) -> &'static str {
+        return match self {
+            ProjectType::Application => "app",
+            ProjectType::Library => "lib",
+        };
+    }
+}
+
+/// A target (task) that can be run for a project, e.g. `build` or `test`.
+#[derive(Clone, Debug, Default, Deserialize, PartialEq, Serialize)]
+pub struct Target {
+    #[serde(default)]
+    pub executor: Option<String>,
+
+    #[serde(default)]
+    pub configurations: BTreeMap<String, Value>,
+}
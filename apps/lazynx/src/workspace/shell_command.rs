@@ -0,0 +1,33 @@
+use std::path::Path;
+
+use tokio::process::Command;
+
+/// Builds the command running a command line through `sh` in the directory.
+pub fn shell_command(command_line: &str, dir: &Path) -> Command {
+    let mut command = Command::new("sh");
+    command.args(["-c", command_line]).current_dir(dir);
+
+    return command;
+}
+
+#[cfg(test)]
+mod shell_command_tests {
+    use super::shell_command;
+
+    use std::path::Path;
+
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_shell_command() {
+        let command = shell_command("npm run lint:all", Path::new("/repo"));
+        let command = command.as_std();
+
+        assert_eq!(command.get_program(), "sh");
+        assert_eq!(
+            command.get_args().collect::<Vec<_>>(),
+            vec!["-c", "npm run lint:all"]
+        );
+        assert_eq!(command.get_current_dir(), Some(Path::new("/repo")));
+    }
+}
@@ -0,0 +1,53 @@
+use std::path::PathBuf;
+
+use color_eyre::eyre::Result;
+use test_utils::TempDir;
+
+const API_PROJECT: &str = r#"{
+  "name": "api",
+  "projectType": "application",
+  "tags": ["scope:api"],
+  "targets": {
+    "build": { "executor": "@nx/js:tsc", "configurations": { "production": {} } },
+    "serve": { "executor": "@nx/js:node" }
+  }
+}"#;
+
+const SHARED_UI_PROJECT: &str = r#"{
+  "name": "shared-ui",
+  "projectType": "library",
+  "tags": ["scope:shared", "type:ui"],
+  "targets": {
+    "lint": { "executor": "@nx/eslint:lint" },
+    "test": { "executor": "@nx/jest:jest" }
+  }
+}"#;
+
+/// A throwaway Nx workspace on disk with an `api` application and a
+/// `shared-ui` library. It is removed again when dropped.
+pub struct MockNxWorkspace {
+    pub root: PathBuf,
+    dir: TempDir,
+}
+
+impl MockNxWorkspace {
+    pub fn new(name: &str) -> Result<Self> {
+        let dir = TempDir::new(name)?;
+
+        let workspace = MockNxWorkspace {
+            root: dir.path.clone(),
+            dir,
+        };
+        workspace.write("nx.json", "{}")?;
+        workspace.write("apps/api/project.json", API_PROJECT)?;
+        workspace.write("libs/shared-ui/project.json", SHARED_UI_PROJECT)?;
+
+        return Ok(workspace);
+    }
+
+    pub fn write(&self, path: &str, contents: &str) -> Result<()> {
+        self.dir.write(path, contents)?;
+
+        Ok(())
+    }
+}
@@ -1,3 +1,5 @@
-mod workspace_test_bed;
+mod mock_nx_workspace;
+pub use mock_nx_workspace::MockNxWorkspace;
 
+mod workspace_test_bed;
 pub use workspace_test_bed::WorkspaceTestBed;
@@ -1,3 +1,21 @@
+mod nx_command;
+pub use nx_command::{nx_run_command, nx_task_name};
+
+mod nx_version;
+pub use nx_version::{find_nx_version, installed_nx_version};
+
+mod project;
+pub use project::{Project, ProjectType};
+
+mod shell_command;
+pub use shell_command::shell_command;
+
+mod workspace_cache;
+pub use workspace_cache::WorkspaceCache;
+
+mod workspace_loader;
+pub use workspace_loader::{find_workspace_root, load_projects};
+
 mod workspace_store;
 
 mod workspace_view_widget;
@@ -0,0 +1,170 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+use color_eyre::eyre::Result;
+use serde::{Deserialize, Serialize};
+
+use super::Project;
+use crate::shared::session::workspace_file_name;
+
+/// The projects of a single workspace as they were last loaded, kept under
+/// `<data_dir>/cache` so shell completions don't have to walk the workspace.
+///
+/// The cache is stale once `nx.json`, a `project.json`, the workspace root or
+/// a directory above a project has been modified since, which is cheap to
+/// check.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WorkspaceCache {
+    file: PathBuf,
+    workspace_root: PathBuf,
+}
+
+#[derive(Deserialize, Serialize)]
+struct CachedProjects {
+    /// When each of the files the projects were loaded from was modified,
+    /// by their path relative to the workspace root.
+    modified: BTreeMap<PathBuf, Option<SystemTime>>,
+    projects: Vec<Project>,
+}
+
+impl WorkspaceCache {
+    pub fn new(data_dir: &Path, workspace_root: &Path) -> Self {
+        let file_name = workspace_file_name(workspace_root, "json");
+
+        return WorkspaceCache {
+            file: data_dir.join("cache").join(file_name),
+            workspace_root: workspace_root.to_path_buf(),
+        };
+    }
+
+    /// The cached projects, or `None` when nothing is cached yet, the cache
+    /// can't be read or it is stale.
+    pub fn load(&self) -> Option<Vec<Project>> {
+        let contents = fs::read_to_string(&self.file).ok()?;
+        let cached: CachedProjects = serde_json::from_str(&contents).ok()?;
+
+        if cached.modified != self.modified_times(&cached.projects) {
+            return None;
+        }
+
+        return Some(cached.projects);
+    }
+
+    pub fn save(&self, projects: &[Project]) -> Result<()> {
+        if let Some(dir) = self.file.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        let cached = CachedProjects {
+            modified: self.modified_times(projects),
+            projects: projects.to_vec(),
+        };
+        fs::write(&self.file, serde_json::to_string(&cached)?)?;
+
+        Ok(())
+    }
+
+    /// When `nx.json`, the `project.json` of each project, the workspace root
+    /// and the directories above each project were last modified. A project
+    /// added in a new directory changes the one the new directory is in.
+    fn modified_times(&self, projects: &[Project]) -> BTreeMap<PathBuf, Option<SystemTime>> {
+        let mut files = vec![PathBuf::from("nx.json"), PathBuf::from(".")];
+        for project in projects {
+            files.push(project.root.join("project.json"));
+            files.extend(
+                project
+                    .root
+                    .ancestors()
+                    .skip(1)
+                    .filter(|dir| !dir.as_os_str().is_empty())
+                    .map(Path::to_path_buf),
+            );
+        }
+
+        return files
+            .into_iter()
+            .map(|file| {
+                let modified = fs::metadata(self.workspace_root.join(&file))
+                    .and_then(|metadata| metadata.modified())
+                    .ok();
+                return (file, modified);
+            })
+            .collect();
+    }
+}
+
+#[cfg(test)]
+mod workspace_cache_tests {
+    use super::WorkspaceCache;
+
+    use std::{
+        fs::{self, FileTimes},
+        thread::sleep,
+        time::{Duration, SystemTime},
+    };
+
+    use color_eyre::eyre::Result;
+    use pretty_assertions::assert_eq;
+    use test_case::test_case;
+    use test_utils::TempDir;
+
+    use crate::workspace::{load_projects, test_bed::MockNxWorkspace};
+
+    #[test]
+    fn test_save_and_load() -> Result<()> {
+        let workspace = MockNxWorkspace::new("workspace_cache_save")?;
+        let data_dir = TempDir::new("workspace_cache_save-data")?;
+        let cache = WorkspaceCache::new(&data_dir.path, &workspace.root);
+        assert_eq!(cache.load(), None);
+
+        let projects = load_projects(&workspace.root)?;
+        cache.save(&projects)?;
+
+        assert_eq!(cache.load(), Some(projects));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_changed_project_makes_cache_stale() -> Result<()> {
+        let workspace = MockNxWorkspace::new("workspace_cache_stale")?;
+        let data_dir = TempDir::new("workspace_cache_stale-data")?;
+        let cache = WorkspaceCache::new(&data_dir.path, &workspace.root);
+        let projects = load_projects(&workspace.root)?;
+        cache.save(&projects)?;
+
+        let project_file = workspace.root.join(&projects[0].root).join("project.json");
+        let later = SystemTime::now() + Duration::from_secs(60);
+        fs::File::options()
+            .write(true)
+            .open(project_file)?
+            .set_times(FileTimes::new().set_modified(later))?;
+
+        assert_eq!(cache.load(), None, "The cache should be stale.");
+
+        Ok(())
+    }
+
+    #[test_case("apps/web/project.json"; "Next To A Project")]
+    #[test_case("tools/scripts/project.json"; "In A New Directory")]
+    #[test_case("libs/feature/auth/project.json"; "Nested In A New Directory")]
+    fn test_added_project_makes_cache_stale(project_file: &str) -> Result<()> {
+        let name = project_file.replace('/', "_");
+        let workspace = MockNxWorkspace::new(&format!("workspace_cache_added_{name}"))?;
+        let data_dir = TempDir::new(&format!("workspace_cache_added_{name}-data"))?;
+        let cache = WorkspaceCache::new(&data_dir.path, &workspace.root);
+        cache.save(&load_projects(&workspace.root)?)?;
+        // Directory times may only be precise to the second.
+        sleep(Duration::from_millis(1100));
+
+        workspace.write(project_file, r#"{ "name": "new" }"#)?;
+
+        assert_eq!(cache.load(), None, "The cache should be stale.");
+
+        Ok(())
+    }
+}
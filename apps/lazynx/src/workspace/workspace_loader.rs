@@ -0,0 +1,131 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use color_eyre::eyre::Result;
+
+use super::project::Project;
+
+/// Directories that never contain projects and are expensive to walk.
+const IGNORED_DIRS: [&str; 7] = [
+    ".angular",
+    ".git",
+    ".nx",
+    "dist",
+    "node_modules",
+    "target",
+    "tmp",
+];
+
+/// Walks up from `start` to the closest directory containing an `nx.json`.
+pub fn find_workspace_root(start: &Path) -> Option<PathBuf> {
+    return start
+        .ancestors()
+        .find(|dir| dir.join("nx.json").is_file())
+        .map(Path::to_path_buf);
+}
+
+/// Loads every project in the workspace from its `project.json`, sorted by
+/// name. Projects whose `project.json` cannot be parsed are skipped with a
+/// warning.
+pub fn load_projects(root: &Path) -> Result<Vec<Project>> {
+    let mut projects = Vec::new();
+    collect_projects(root, root, &mut projects)?;
+    projects.sort_by(|a, b| a.name.cmp(&b.name));
+
+    return Ok(projects);
+}
+
+fn collect_projects(root: &Path, dir: &Path, projects: &mut Vec<Project>) -> Result<()> {
+    let project_file = dir.join("project.json");
+    if project_file.is_file() {
+        match read_project(root, dir, &project_file) {
+            Ok(project) => projects.push(project),
+            Err(error) => tracing::warn!("Skipping {}: {error}", project_file.display()),
+        }
+    }
+
+    for entry in fs::read_dir(dir)?.flatten() {
+        let Ok(file_type) = entry.file_type() else {
+            continue;
+        };
+        let name = entry.file_name();
+        let is_ignored = IGNORED_DIRS.iter().any(|ignored| name == *ignored);
+
+        if file_type.is_dir() && !is_ignored {
+            collect_projects(root, &entry.path(), projects)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn read_project(root: &Path, dir: &Path, project_file: &Path) -> Result<Project> {
+    let mut project: Project = serde_json::from_str(&fs::read_to_string(project_file)?)?;
+    project.root = dir.strip_prefix(root).unwrap_or(dir).to_path_buf();
+
+    if project.name.is_empty() {
+        project.name = dir
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+    }
+
+    return Ok(project);
+}
+
+#[cfg(test)]
+mod workspace_loader_tests {
+    use super::{find_workspace_root, load_projects};
+
+    use std::{fs, path::PathBuf};
+
+    use color_eyre::eyre::Result;
+    use pretty_assertions::assert_eq;
+
+    use crate::workspace::{project::ProjectType, test_bed::MockNxWorkspace};
+
+    #[test]
+    fn test_find_workspace_root() -> Result<()> {
+        let workspace = MockNxWorkspace::new("find_workspace_root")?;
+        let nested = workspace.root.join("apps").join("api").join("src");
+        fs::create_dir_all(&nested)?;
+
+        assert_eq!(find_workspace_root(&nested), Some(workspace.root.clone()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_projects() -> Result<()> {
+        let workspace = MockNxWorkspace::new("load_projects")?;
+
+        let projects = load_projects(&workspace.root)?;
+        let names: Vec<&str> = projects.iter().map(|p| p.name.as_str()).collect();
+
+        assert_eq!(names, vec!["api", "shared-ui"]);
+        assert_eq!(projects[0].root, PathBuf::from("apps/api"));
+        assert_eq!(projects[0].project_type, Some(ProjectType::Application));
+        assert_eq!(projects[0].tags, vec![String::from("scope:api")]);
+        assert_eq!(
+            projects[0].targets.keys().collect::<Vec<_>>(),
+            vec!["build", "serve"]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_projects_skips_invalid_and_ignored() -> Result<()> {
+        let workspace = MockNxWorkspace::new("load_projects_skips")?;
+        workspace.write("apps/broken/project.json", "{ not json")?;
+        workspace.write("node_modules/pkg/project.json", r#"{ "name": "pkg" }"#)?;
+
+        let projects = load_projects(&workspace.root)?;
+
+        assert_eq!(projects.len(), 2, "Unexpected projects: {projects:?}");
+
+        Ok(())
+    }
+}
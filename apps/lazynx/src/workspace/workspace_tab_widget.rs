@@ -1,39 +1,38 @@
+use color_eyre::eyre::Result;
 use ratatui::{
     buffer::Buffer,
     layout::{Alignment, Rect},
     text::Text,
     widgets::{Block, BorderType, Borders, Padding, Paragraph, Widget},
+    Frame,
 };
 
 use super::workspace_store::{WorkspaceAction, WorkspaceStore};
+use crate::shared::{
+    actions::Action, component::Component, config::Config, focus::Pane, theme::Theme,
+};
 
-#[derive(Default, Clone)]
+#[derive(Default)]
 pub struct WorkspaceTabWidget {
+    is_focused: bool,
     store: WorkspaceStore,
+    theme: Theme,
 }
 
 impl WorkspaceTabWidget {
-    pub fn init(&mut self) -> color_eyre::eyre::Result<()> {
-        self.store.update(WorkspaceAction::SetWorkspaceName);
-
-        Ok(())
-    }
-
-    fn create_tab(&self) -> Block {
+    fn create_tab(&self) -> Block<'_> {
         return Block::default()
             .title("─[1]─Workspace─")
             .title_alignment(Alignment::Left)
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
+            .border_style(self.theme.border_style(self.is_focused))
             .padding(Padding::left(3));
     }
 }
 
-impl Widget for WorkspaceTabWidget {
-    fn render(self, area: Rect, buf: &mut Buffer)
-    where
-        Self: Sized,
-    {
+impl Widget for &WorkspaceTabWidget {
+    fn render(self, area: Rect, buf: &mut Buffer) {
         let block = self.create_tab();
         let workspace_name = Text::from(self.store.get_workspace_name());
 
@@ -44,9 +43,34 @@ impl Widget for WorkspaceTabWidget {
     }
 }
 
+impl Component for WorkspaceTabWidget {
+    fn register_config_handler(&mut self, config: Config) -> Result<()> {
+        self.theme = config.theme;
+        Ok(())
+    }
+
+    fn init(&mut self) -> Result<()> {
+        self.store.update(WorkspaceAction::SetWorkspaceName);
+
+        Ok(())
+    }
+
+    fn update(&mut self, action: Action) -> Result<Option<Action>> {
+        if let Action::Focus(pane) = action {
+            self.is_focused = pane == Pane::Workspace;
+        }
+
+        Ok(None)
+    }
+
+    fn draw(&mut self, frame: &mut Frame, area: Rect) {
+        frame.render_widget(&*self, area);
+    }
+}
+
 #[cfg(test)]
 mod workspace_tab_widget_tests {
-    use crate::workspace::test_bed::WorkspaceTestBed;
+    use crate::{shared::component::Component, workspace::test_bed::WorkspaceTestBed};
 
     use super::WorkspaceTabWidget;
 
@@ -69,7 +93,7 @@ mod workspace_tab_widget_tests {
         test_bed
             .widget
             .terminal
-            .draw(|f| f.render_widget(test_bed.widget.widget, f.area()))
+            .draw(|f| f.render_widget(&test_bed.widget.widget, f.area()))
             .unwrap();
 
         assert_snapshot!(test_bed.widget.terminal.backend());
@@ -1,15 +1,39 @@
 use std::rc::Rc;
 
 use chrono::{DateTime, Datelike, Local};
+use color_eyre::eyre::Result;
+use crossterm::event::Event as CrosstermEvent;
 use ratatui::{
     buffer::Buffer,
     prelude::{Constraint, Direction, Layout, Line, Rect, Span},
     widgets::{Block, BorderType, Borders, Padding, Paragraph, Widget},
+    Frame,
 };
 
-#[derive(Clone)]
+use super::nx_task_name;
+use crate::shared::{
+    actions::Action,
+    component::Component,
+    config::Config,
+    events::Event,
+    focus::Pane,
+    keymap::{KeyContext, Keymap},
+    process::{ExitStatus, ProcessEvent, ProcessOutput},
+    session::Session,
+    theme::Theme,
+};
+
+/// How many lines of task output are kept around.
+const MAX_OUTPUT_LINES: usize = 1000;
+
 pub struct WorkspaceViewWidget {
     date: DateTime<Local>,
+    is_focused: bool,
+    keymap: Keymap,
+    output: Vec<String>,
+    /// How many lines the output is scrolled up from its end.
+    scroll: u16,
+    theme: Theme,
 }
 
 impl Default for WorkspaceViewWidget {
@@ -20,14 +44,22 @@ impl Default for WorkspaceViewWidget {
 
 impl WorkspaceViewWidget {
     pub fn new(date: DateTime<Local>) -> Self {
-        return WorkspaceViewWidget { date };
+        return WorkspaceViewWidget {
+            date,
+            is_focused: false,
+            keymap: Keymap::default(),
+            output: Vec::new(),
+            scroll: 0,
+            theme: Theme::default(),
+        };
     }
 
-    fn create_block(&self) -> Block {
+    fn create_block(&self) -> Block<'_> {
         return Block::default()
-            .title(Line::from("─Workspace").left_aligned())
+            .title(Line::from("─[0]─Workspace").left_aligned())
             .borders(Borders::ALL)
             .border_type(BorderType::Rounded)
+            .border_style(self.theme.border_style(self.is_focused))
             .padding(Padding::horizontal(2));
     }
 
@@ -40,7 +72,7 @@ impl WorkspaceViewWidget {
 
     // TODO: Remember to make the snapshot unit test deterministic when it comes
     //-      to the copyright year.
-    fn get_copyright_paragraph(&self) -> Paragraph {
+    fn get_copyright_paragraph(&self) -> Paragraph<'_> {
         let copyright_year = Datelike::year(&self.date);
         let copyright_icon = String::from('\u{00A9}');
         let copyright = Span::from(format!(
@@ -51,7 +83,38 @@ impl WorkspaceViewWidget {
         return Paragraph::new(copyright);
     }
 
-    fn get_header(&self) -> Paragraph {
+    /// Adds a line of output. While scrolled up, the visible lines stay put.
+    fn push_output(&mut self, line: String) {
+        self.output.push(line);
+
+        let overflow = self.output.len().saturating_sub(MAX_OUTPUT_LINES);
+        self.output.drain(..overflow);
+
+        if self.scroll > 0 {
+            self.scroll_by(1);
+        }
+    }
+
+    /// Scrolls the output up by the number of lines, or down if negative.
+    fn scroll_by(&mut self, lines: i32) {
+        let max_scroll = self.output.len().saturating_sub(1) as i32;
+
+        self.scroll = (i32::from(self.scroll) + lines).clamp(0, max_scroll) as u16;
+    }
+
+    /// The output lines that fit in the area, ending where it is scrolled to.
+    fn get_output_paragraph(&self, height: u16) -> Paragraph<'_> {
+        let end = self.output.len().saturating_sub(self.scroll as usize);
+        let start = end.saturating_sub(height as usize);
+        let lines: Vec<Line> = self.output[start..end]
+            .iter()
+            .map(|line| Line::from(line.as_str()))
+            .collect();
+
+        return Paragraph::new(lines);
+    }
+
+    fn get_header(&self) -> Paragraph<'_> {
         // NOTE: Don't change the whitespace or alignment for ASCII art text.
         //       Any changes to them will be reflected in the app itself.
         let lazynx_title = String::from(
@@ -71,12 +134,19 @@ impl WorkspaceViewWidget {
     }
 }
 
-impl Widget for WorkspaceViewWidget {
+impl Widget for &WorkspaceViewWidget {
     fn render(self, area: Rect, buf: &mut Buffer) {
         let block = self.create_block();
-        let chunks = self.create_layout(block.inner(area));
+        let inner = block.inner(area);
         block.render(area, buf);
 
+        if !self.output.is_empty() {
+            self.get_output_paragraph(inner.height).render(inner, buf);
+            return;
+        }
+
+        let chunks = self.create_layout(inner);
+
         let header = self.get_header();
         header.render(chunks[0], buf);
 
@@ -85,13 +155,102 @@ impl Widget for WorkspaceViewWidget {
     }
 }
 
+impl Component for WorkspaceViewWidget {
+    fn register_config_handler(&mut self, config: Config) -> Result<()> {
+        self.keymap = config.keymap;
+        self.theme = config.theme;
+        Ok(())
+    }
+
+    fn restore_session(&mut self, session: &Session) -> Result<()> {
+        // The output isn't kept between launches, so the scroll is clamped to
+        // whatever output there is.
+        self.scroll = 0;
+        self.scroll_by(session.output_scroll.into());
+        Ok(())
+    }
+
+    fn save_session(&self, session: &mut Session) {
+        session.output_scroll = self.scroll;
+    }
+
+    fn handle_events(&mut self, event: Event) -> Option<Action> {
+        let Event::Crossterm(CrosstermEvent::Key(key)) = event else {
+            return None;
+        };
+
+        match self.keymap.action_for(KeyContext::Main, &key) {
+            Some(Action::ScrollUp) => self.scroll_by(1),
+            Some(Action::ScrollDown) => self.scroll_by(-1),
+            action => return action,
+        }
+
+        None
+    }
+
+    fn update(&mut self, action: Action) -> Result<Option<Action>> {
+        match action {
+            Action::Focus(pane) => self.is_focused = pane == Pane::Main,
+            Action::RunCommand(command) => {
+                self.output.clear();
+                self.scroll = 0;
+                self.push_output(format!("> {command}"));
+            }
+            Action::RunTarget {
+                project,
+                target,
+                configuration,
+            } => {
+                let task = nx_task_name(&project, &target, configuration.as_deref());
+                self.output.clear();
+                self.scroll = 0;
+                self.push_output(format!("> nx run {task}"));
+            }
+            Action::ProcessOutput(ProcessOutput { event, .. }) => match event {
+                ProcessEvent::Stdout(line) | ProcessEvent::Stderr(line) => self.push_output(line),
+                ProcessEvent::Exited(ExitStatus::Code(code)) => {
+                    self.push_output(format!("Process exited with code {code}"));
+                }
+                ProcessEvent::Exited(ExitStatus::Signal(signal)) => {
+                    self.push_output(format!("Process was terminated by signal {signal}"));
+                }
+            },
+            _ => {}
+        }
+
+        Ok(None)
+    }
+
+    fn draw(&mut self, frame: &mut Frame, area: Rect) {
+        frame.render_widget(&*self, area);
+    }
+}
+
 #[cfg(test)]
 mod workspace_widget_tests {
     use super::WorkspaceViewWidget;
 
+    use color_eyre::eyre::Result;
+    use crossterm::event::{Event as CrosstermEvent, KeyCode, KeyEvent, KeyModifiers};
     use insta::assert_snapshot;
+    use pretty_assertions::assert_eq;
     use test_utils::{mocks::MOCK_DATE, WidgetTestBed};
 
+    use crate::shared::{
+        actions::Action,
+        component::Component,
+        events::Event,
+        process::{ExitStatus, ProcessEvent, ProcessId, ProcessOutput},
+        session::Session,
+    };
+
+    fn output(event: ProcessEvent) -> Action {
+        return Action::ProcessOutput(ProcessOutput {
+            id: ProcessId(0),
+            event,
+        });
+    }
+
     #[test]
     fn test_workspace_view_widget() {
         let widget = WorkspaceViewWidget::new(*MOCK_DATE);
@@ -99,9 +258,76 @@ mod workspace_widget_tests {
 
         test_bed
             .terminal
-            .draw(|f| f.render_widget(test_bed.widget, f.area()))
+            .draw(|f| f.render_widget(&test_bed.widget, f.area()))
             .unwrap();
 
         assert_snapshot!(test_bed.terminal.backend());
     }
+
+    #[test]
+    fn test_task_output() -> Result<()> {
+        let mut widget = WorkspaceViewWidget::new(*MOCK_DATE);
+
+        widget.update(Action::RunTarget {
+            project: String::from("api"),
+            target: String::from("build"),
+            configuration: None,
+        })?;
+        widget.update(output(ProcessEvent::Stdout(String::from(
+            "Compiled successfully",
+        ))))?;
+        widget.update(output(ProcessEvent::Exited(ExitStatus::Code(0))))?;
+
+        assert_eq!(
+            widget.output,
+            vec![
+                "> nx run api:build",
+                "Compiled successfully",
+                "Process exited with code 0"
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_scroll_output() -> Result<()> {
+        let mut widget = WorkspaceViewWidget::new(*MOCK_DATE);
+        let key = |code| {
+            Event::Crossterm(CrosstermEvent::Key(KeyEvent::new(
+                code,
+                KeyModifiers::empty(),
+            )))
+        };
+        for line in ["one", "two", "three"] {
+            widget.update(output(ProcessEvent::Stdout(String::from(line))))?;
+        }
+
+        widget.handle_events(key(KeyCode::Char('k')));
+        widget.handle_events(key(KeyCode::Char('k')));
+        widget.handle_events(key(KeyCode::Char('k')));
+        assert_eq!(widget.scroll, 2, "The first line should stay in view.");
+
+        widget.update(output(ProcessEvent::Stdout(String::from("four"))))?;
+        assert_eq!(widget.scroll, 3, "The visible lines should stay put.");
+
+        widget.handle_events(key(KeyCode::Char('j')));
+        assert_eq!(widget.scroll, 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_restored_scroll_is_clamped_without_output() -> Result<()> {
+        let mut widget = WorkspaceViewWidget::new(*MOCK_DATE);
+
+        widget.restore_session(&Session {
+            output_scroll: 40,
+            ..Session::default()
+        })?;
+
+        assert_eq!(widget.scroll, 0, "There is no output to scroll.");
+
+        Ok(())
+    }
 }
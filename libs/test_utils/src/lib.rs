@@ -1,3 +1,6 @@
+mod temp_dir;
+pub use temp_dir::TempDir;
+
 mod widget_test_bed;
 pub use widget_test_bed::WidgetTestBed;
 
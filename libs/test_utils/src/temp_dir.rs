@@ -0,0 +1,50 @@
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+    process,
+};
+
+/// A directory of a test's own under `<tmp>/lazynx-tests`. It starts out
+/// empty and is removed again when dropped.
+pub struct TempDir {
+    pub path: PathBuf,
+}
+
+impl TempDir {
+    /// Creates `<tmp>/lazynx-tests/<name>-<pid>`, clearing out whatever an
+    /// earlier run left behind. The name has to be unique among the tests.
+    pub fn new(name: &str) -> io::Result<Self> {
+        let path = env::temp_dir()
+            .join("lazynx-tests")
+            .join(format!("{name}-{}", process::id()));
+
+        if path.exists() {
+            fs::remove_dir_all(&path)?;
+        }
+        fs::create_dir_all(&path)?;
+
+        return Ok(TempDir { path });
+    }
+
+    pub fn join(&self, path: impl AsRef<Path>) -> PathBuf {
+        return self.path.join(path);
+    }
+
+    /// Writes the file, creating the directories leading up to it, and
+    /// returns its path.
+    pub fn write(&self, path: impl AsRef<Path>, contents: &str) -> io::Result<PathBuf> {
+        let path = self.path.join(path);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(&path, contents)?;
+
+        return Ok(path);
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}